@@ -26,3 +26,4 @@ export { TextField } from '@material/mwc-textfield'
 export { TextArea } from '@material/mwc-textarea'
 export { Select } from '@material/mwc-select'
 export { Menu } from '@material/mwc-menu'
+export { MenuSurface } from '@material/mwc-menu/mwc-menu-surface'
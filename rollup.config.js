@@ -30,6 +30,7 @@ const COMPONENTS = [
     "textarea",
     "select",
     "menu",
+    "menu/mwc-menu-surface",
 ];
 
 export default {
@@ -1,11 +1,12 @@
 use crate::components::Codeblock;
 use crate::with_raw_code;
 use std::borrow::Cow;
+use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 use yew::services::ConsoleService;
 use yew_material::{
-    dialog::{ActionType, MatDialogAction},
-    MatButton, MatDialog, WeakComponentLink,
+    dialog::{ActionType, BeforeCloseEvent, DialogAction, MatDialogAction},
+    dialog_service, MatButton, MatDialog, MatFullScreenDialog, MatTextField, WeakComponentLink,
 };
 
 pub struct Dialog {
@@ -15,7 +16,13 @@ pub struct Dialog {
     scrollable_dialog_link: WeakComponentLink<MatDialog>,
     hide_action_dialog_link: WeakComponentLink<MatDialog>,
     stacked_dialog_link: WeakComponentLink<MatDialog>,
+    outer_dialog_link: WeakComponentLink<MatDialog>,
+    inner_dialog_link: WeakComponentLink<MatDialog>,
+    form_dialog_link: WeakComponentLink<MatDialog>,
+    full_screen_dialog_link: WeakComponentLink<MatDialog>,
     hide_actions: bool,
+    name: String,
+    service_result: String,
 }
 
 pub enum Msg {
@@ -24,7 +31,17 @@ pub enum Msg {
     ShowScrollableDialog,
     ShowHideActionDialog,
     ShowStackedDialog,
+    ShowOuterDialog,
+    ShowInnerDialog,
+    QueueDialogs,
+    ShowFormDialog,
+    ShowFullScreenDialog,
+    NameInput(String),
+    BeforeFormClose(BeforeCloseEvent<String>),
     HideActions,
+    Confirm,
+    Prompt,
+    ServiceResult(String),
 }
 
 impl Component for Dialog {
@@ -39,7 +56,13 @@ impl Component for Dialog {
             scrollable_dialog_link: Default::default(),
             hide_action_dialog_link: Default::default(),
             stacked_dialog_link: Default::default(),
+            outer_dialog_link: Default::default(),
+            inner_dialog_link: Default::default(),
+            form_dialog_link: Default::default(),
+            full_screen_dialog_link: Default::default(),
             hide_actions: false,
+            name: String::new(),
+            service_result: String::new(),
         }
     }
 
@@ -65,10 +88,65 @@ impl Component for Dialog {
                 self.stacked_dialog_link.show();
                 false
             }
+            Msg::ShowOuterDialog => {
+                self.outer_dialog_link.show_stacked();
+                false
+            }
+            Msg::ShowInnerDialog => {
+                self.inner_dialog_link.show_stacked();
+                false
+            }
+            Msg::QueueDialogs => {
+                self.outer_dialog_link.show_queued();
+                self.stacked_dialog_link.show_queued();
+                false
+            }
             Msg::HideActions => {
                 self.hide_actions = !self.hide_actions;
                 true
             }
+            Msg::Confirm => {
+                let link = self.link.clone();
+                spawn_local(async move {
+                    let confirmed =
+                        dialog_service::confirm("Discard draft?", "Your changes will be lost.")
+                            .await;
+                    link.send_message(Msg::ServiceResult(format!("Confirmed: {}", confirmed)));
+                });
+                false
+            }
+            Msg::Prompt => {
+                let link = self.link.clone();
+                spawn_local(async move {
+                    let name = dialog_service::prompt("Rename file", "Name", "untitled.txt").await;
+                    link.send_message(Msg::ServiceResult(format!("Entered: {:?}", name)));
+                });
+                false
+            }
+            Msg::ShowFormDialog => {
+                self.form_dialog_link.show();
+                false
+            }
+            Msg::ShowFullScreenDialog => {
+                self.full_screen_dialog_link.show();
+                false
+            }
+            Msg::NameInput(name) => {
+                self.name = name;
+                false
+            }
+            Msg::BeforeFormClose(event) => {
+                if *event.action() == DialogAction::Custom("save".to_string())
+                    && self.name.is_empty()
+                {
+                    event.prevent_default();
+                }
+                false
+            }
+            Msg::ServiceResult(result) => {
+                self.service_result = result;
+                true
+            }
         }
     }
 
@@ -86,10 +164,10 @@ impl Component for Dialog {
                 onclosing=Callback::from(|action| ConsoleService::log(&format!("onclosing action: {}", action)))
                 onclosed=Callback::from(|action| ConsoleService::log(&format!("closed action: {}", action)))>
                 {"Dialog body text"}
-                <MatDialogAction action_type=ActionType::Primary action=Cow::from("ok")>
+                <MatDialogAction action_type=ActionType::Primary action=DialogAction::Custom(String::from("ok"))>
                     <MatButton label="Action 2" />
                 </MatDialogAction>
-                <MatDialogAction action_type=ActionType::Secondary action=Cow::from("cancel")>
+                <MatDialogAction action_type=ActionType::Secondary action=DialogAction::Custom(String::from("cancel"))>
                     <MatButton label="Action 1" />
                 </MatDialogAction>
             </MatDialog>
@@ -109,7 +187,7 @@ impl Component for Dialog {
                     \"closed\" event with an event detail of {action: \"my-action\"}
                 "}
 
-                <MatDialogAction action_type=ActionType::Primary action=Cow::from("customAction")>
+                <MatDialogAction action_type=ActionType::Primary action=DialogAction::Custom(String::from("customAction"))>
                     <MatButton label="This has action" />
                 </MatDialogAction>
                 <MatDialogAction action_type=ActionType::Secondary>
@@ -129,7 +207,7 @@ impl Component for Dialog {
             </span>
             <MatDialog heading=Cow::from("Scrollable") dialog_link=self.scrollable_dialog_link.clone()>
                 {text}
-                <MatDialogAction action_type=ActionType::Primary action=Cow::from("close")>
+                <MatDialogAction action_type=ActionType::Primary action=DialogAction::Close>
                     <MatButton label="Close this!" />
                 </MatDialogAction>
             </MatDialog>
@@ -165,15 +243,89 @@ impl Component for Dialog {
                     This is what happens when you set the stacked property on mwc-dialog.
                     Notice that the primary action is now on top.
                 "}
-                <MatDialogAction action_type=ActionType::Primary action=Cow::from("close")>
+                <MatDialogAction action_type=ActionType::Primary action=DialogAction::Close>
                     <MatButton label="Primary" />
                 </MatDialogAction>
-                <MatDialogAction action_type=ActionType::Secondary action=Cow::from("close")>
+                <MatDialogAction action_type=ActionType::Secondary action=DialogAction::Close>
                     <MatButton label="Secondary" />
                 </MatDialogAction>
             </MatDialog>
         </section>
         }});
+        let nested = with_raw_code!(nested { html! {
+        <section>
+            <span onclick=self.link.callback(|_| Msg::ShowOuterDialog)>
+                <MatButton raised=true label="Nested" />
+            </span>
+            <span onclick=self.link.callback(|_| Msg::QueueDialogs)>
+                <MatButton raised=true label="Queued" />
+            </span>
+            <MatDialog heading=Cow::from("Outer") dialog_link=self.outer_dialog_link.clone()>
+                <span onclick=self.link.callback(|_| Msg::ShowInnerDialog)>
+                    <MatButton label="Open another dialog" />
+                </span>
+                <MatDialogAction action_type=ActionType::Primary action=DialogAction::Close>
+                    <MatButton label="Close" />
+                </MatDialogAction>
+            </MatDialog>
+            <MatDialog heading=Cow::from("Inner") dialog_link=self.inner_dialog_link.clone()>
+                {"Pressing Escape closes only this dialog."}
+                <MatDialogAction action_type=ActionType::Primary action=DialogAction::Close>
+                    <MatButton label="Close" />
+                </MatDialogAction>
+            </MatDialog>
+        </section>
+        }});
+
+        let before_close = with_raw_code!(before_close { html! {
+        <section>
+            <span onclick=self.link.callback(|_| Msg::ShowFormDialog)>
+                <MatButton raised=true label="Vetoable" />
+            </span>
+            <MatDialog heading=Cow::from("Name") dialog_link=self.form_dialog_link.clone()
+                onbeforeclose=self.link.callback(Msg::BeforeFormClose)>
+                <p>{"The dialog can't be saved while the name is empty."}</p>
+                <MatTextField label="Name" oninput=self.link.callback(|data: InputData| Msg::NameInput(data.value)) />
+                <MatDialogAction action_type=ActionType::Primary action=DialogAction::Custom(String::from("save"))>
+                    <MatButton label="Save" />
+                </MatDialogAction>
+                <MatDialogAction action_type=ActionType::Secondary action=DialogAction::Custom(String::from("cancel"))>
+                    <MatButton label="Cancel" />
+                </MatDialogAction>
+            </MatDialog>
+        </section>
+        }});
+
+        let full_screen = with_raw_code!(full_screen { html! {
+        <section>
+            <span onclick=self.link.callback(|_| Msg::ShowFullScreenDialog)>
+                <MatButton raised=true label="Full-screen" />
+            </span>
+            <MatFullScreenDialog
+                heading=Cow::from("New event")
+                action_label=Cow::from("Save")
+                action=String::from("save")
+                max_width=600
+                dialog_link=self.full_screen_dialog_link.clone()
+            >
+                <p>{"This dialog covers the screen when the window is at most 600px wide."}</p>
+                <MatTextField label="Title" />
+            </MatFullScreenDialog>
+        </section>
+        }});
+
+        let service = with_raw_code!(service { html! {
+        <section>
+            <span onclick=self.link.callback(|_| Msg::Confirm)>
+                <MatButton raised=true label="Confirm" />
+            </span>
+            <span onclick=self.link.callback(|_| Msg::Prompt)>
+                <MatButton raised=true label="Prompt" />
+            </span>
+            <p>{ &self.service_result }</p>
+        </section>
+        }});
+
         /*
         let initial_focus = with_raw_code!(initial_focus { html! {
 
@@ -194,6 +346,14 @@ impl Component for Dialog {
             <Codeblock code_and_html=hide_actions title="Hide Actions" />
 
             <Codeblock code_and_html=stacked title="Stacked" />
+
+            <Codeblock code_and_html=nested title="Nested and queued" />
+
+            <Codeblock code_and_html=before_close title="Vetoable closing" />
+
+            <Codeblock code_and_html=full_screen title="Full-screen" />
+
+            <Codeblock code_and_html=service title="Confirm and prompt service" />
         </main>
         }
     }
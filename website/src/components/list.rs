@@ -1,9 +1,13 @@
 use crate::components::Codeblock;
 use crate::with_raw_code;
+use std::rc::Rc;
+use std::time::Duration;
 use yew::prelude::*;
-use yew_material::list::ListIndex;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew_material::list::{ListIndex, ListItems, LoadingIndicator, SelectedDetail};
 use yew_material::{
-    MatButton, MatCheckListItem, MatList, MatListItem, MatRadioListItem, WeakComponentLink,
+    MatButton, MatCheckListItem, MatList, MatListItem, MatRadioListItem, MatVirtualList,
+    WeakComponentLink,
 };
 
 pub struct List {
@@ -14,11 +18,26 @@ pub struct List {
     checklist_selected_index: String,
     radio_selected_index: String,
     multi_selected_index: String,
+    virtual_selected_index: String,
+    fruits: Rc<[(u32, &'static str)]>,
+    selected_fruits: String,
+    controlled_history: Vec<ListIndex>,
+    tasks: Vec<&'static str>,
+    feed_len: usize,
+    feed_fetch: Option<TimeoutTask>,
 }
 
 pub enum Msg {
     Action(ListIndex, &'static str),
     Focus,
+    SelectLast,
+    FruitsSelected(Vec<(u32, &'static str)>),
+    ReverseFruits,
+    ControlledSelected(SelectedDetail),
+    Undo,
+    Reorder((usize, usize)),
+    LoadMore,
+    Loaded,
 }
 
 impl Component for List {
@@ -33,6 +52,13 @@ impl Component for List {
             checklist_selected_index: "".to_string(),
             radio_selected_index: "".to_string(),
             multi_selected_index: "".to_string(),
+            virtual_selected_index: "".to_string(),
+            fruits: Rc::from(vec![(0, "Apple"), (1, "Banana"), (2, "Cherry"), (3, "Durian")]),
+            selected_fruits: "".to_string(),
+            controlled_history: vec![ListIndex::Single(None)],
+            tasks: vec!["Write code", "Review", "Test", "Release"],
+            feed_len: 20,
+            feed_fetch: None,
             list_link: WeakComponentLink::default(),
         }
     }
@@ -62,6 +88,7 @@ impl Component for List {
                     "checklist" => self.checklist_selected_index = transform(val),
                     "radio" => self.radio_selected_index = transform(val),
                     "multi" => self.multi_selected_index = transform(val),
+                    "virtual" => self.virtual_selected_index = transform(val),
                     _ => {
                         panic!(
                         "dude you fucked up you should've just used an enum or different messages"
@@ -74,6 +101,56 @@ impl Component for List {
                 self.list_link.focus_item_at_index(2);
                 false
             }
+            Msg::SelectLast => {
+                let last = self.list_link.items().len().saturating_sub(1);
+                self.list_link.select(&ListIndex::Single(Some(last)));
+                self.basic_selected_index = transform(self.list_link.index());
+                true
+            }
+            Msg::FruitsSelected(fruits) => {
+                self.selected_fruits = fruits
+                    .iter()
+                    .map(|(_, name)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                true
+            }
+            Msg::ControlledSelected(detail) => {
+                if self.controlled_history.last() != Some(&detail.index) {
+                    self.controlled_history.push(detail.index);
+                }
+                true
+            }
+            Msg::Undo => {
+                if self.controlled_history.len() > 1 {
+                    self.controlled_history.pop();
+                }
+                true
+            }
+            Msg::Reorder((from, to)) => {
+                let task = self.tasks.remove(from);
+                self.tasks.insert(to, task);
+                true
+            }
+            Msg::LoadMore => {
+                // pretend to fetch the next page
+                self.feed_fetch = Some(TimeoutService::spawn(
+                    Duration::from_secs(1),
+                    self.link.callback(|_| Msg::Loaded),
+                ));
+                true
+            }
+            Msg::Loaded => {
+                self.feed_fetch = None;
+                self.feed_len += 20;
+                true
+            }
+            Msg::ReverseFruits => {
+                let mut fruits = self.fruits.to_vec();
+                fruits.reverse();
+                self.fruits = Rc::from(fruits);
+                true
+            }
         }
     }
 
@@ -94,6 +171,9 @@ impl Component for List {
             <div onclick=self.link.callback(|_| Msg::Focus)>
                 <MatButton label="Focus index 2" raised=true />
             </div>
+            <div onclick=self.link.callback(|_| Msg::SelectLast)>
+                <MatButton label="Select last item" raised=true />
+            </div>
         </section>
         }});
 
@@ -163,6 +243,84 @@ impl Component for List {
         </section>
         }});
 
+        let virtual_list = with_raw_code!(virtual_list { html! {
+        <section>
+            <MatVirtualList
+                height="300px"
+                item_count=10_000
+                render_item=MatVirtualList::item_renderer(|index| html! {
+                    <MatListItem>{ format!("Item {}", index) }</MatListItem>
+                })
+                onselected=self.link.callback(|val| Msg::Action(val, "virtual"))
+            />
+
+            <span>{"Selected index: "}{&self.virtual_selected_index}</span>
+        </section>
+        }});
+
+        let data = with_raw_code!(data { html! {
+        <section>
+            <MatList<(u32, &'static str)>
+                multi=true
+                items=ListItems::new(
+                    Rc::clone(&self.fruits),
+                    |(id, _)| (*id).into(),
+                    |(_, name)| html! { <MatCheckListItem>{ *name }</MatCheckListItem> },
+                )
+                onchange=self.link.callback(Msg::FruitsSelected)
+            />
+
+            <span>{"Selected: "}{&self.selected_fruits}</span>
+            <div onclick=self.link.callback(|_| Msg::ReverseFruits)>
+                <MatButton label="Reverse" raised=true />
+            </div>
+        </section>
+        }});
+
+        let controlled = with_raw_code!(controlled { html! {
+        <section>
+            <MatList
+                activatable=true
+                selected=self.controlled_history.last().cloned()
+                onselected=self.link.callback(Msg::ControlledSelected)
+            >
+                <MatListItem>{"Item 0"}</MatListItem>
+                <MatListItem>{"Item 1"}</MatListItem>
+                <MatListItem>{"Item 2"}</MatListItem>
+                <MatListItem>{"Item 3"}</MatListItem>
+            </MatList>
+
+            <div onclick=self.link.callback(|_| Msg::Undo)>
+                <MatButton label="Undo" raised=true />
+            </div>
+        </section>
+        }});
+
+        let reorderable = with_raw_code!(reorderable { html! {
+        <section>
+            <MatList reorderable=true onreorder=self.link.callback(Msg::Reorder)>
+                { for self.tasks.iter().map(|task| html! {
+                    <MatListItem key=*task>{ *task }</MatListItem>
+                }) }
+            </MatList>
+        </section>
+        }});
+
+        let infinite_scroll = with_raw_code!(infinite_scroll { html! {
+        <section style="height: 300px; overflow-y: auto;">
+            <MatList
+                infinite_scroll=true
+                loading=self.feed_fetch.is_some()
+                loading_indicator=LoadingIndicator::Circular
+                onendreached=self.link.callback(|_| Msg::LoadMore)
+            >
+                { for (0..self.feed_len).map(|index| html! {
+                    <MatListItem>{ format!("Post {}", index) }</MatListItem>
+                }) }
+            </MatList>
+        </section>
+        }});
+
         html! {<main class="list-demo">
             <Codeblock title="Basic" code_and_html=basic />
             <Codeblock title="Multi + Activatable" code_and_html=multi_activatable />
@@ -170,6 +328,11 @@ impl Component for List {
             <Codeblock title="Non-interactive" code_and_html=non_interactive />
             <Codeblock title="Checklist" code_and_html=checklist />
             <Codeblock title="Radio list" code_and_html=radio_list />
+            <Codeblock title="Virtual list" code_and_html=virtual_list />
+            <Codeblock title="Data" code_and_html=data />
+            <Codeblock title="Controlled" code_and_html=controlled />
+            <Codeblock title="Reorderable" code_and_html=reorderable />
+            <Codeblock title="Infinite scroll" code_and_html=infinite_scroll />
         </main>}
     }
 }
@@ -1,32 +1,38 @@
 use crate::components::Codeblock;
 use crate::with_raw_code;
+use std::borrow::Cow;
 use yew::prelude::*;
-use yew_material::menu::{Corner, DefaultFocusState};
-use yew_material::{MatButton, MatListItem, MatMenu, WeakComponentLink};
+use yew_material::list::ListOption;
+use yew_material::menu::{ContextMenuAction, Corner, DefaultFocusState};
+use yew_material::{
+    MatButton, MatContextMenu, MatListItem, MatMenu, MatMenuButton, MatMenuSurface, MatSubMenu,
+    WeakComponentLink,
+};
 
 pub struct Menu {
     link: ComponentLink<Self>,
-    basic_menu_link: WeakComponentLink<MatMenu>,
-    corner_menu_link: WeakComponentLink<MatMenu>,
-    quick_menu_link: WeakComponentLink<MatMenu>,
-    fixed_menu_link: WeakComponentLink<MatMenu>,
-    non_fixed_menu_link: WeakComponentLink<MatMenu>,
     absolute_menu_no_anchor_link: WeakComponentLink<MatMenu>,
-    activatable_menu_link: WeakComponentLink<MatMenu>,
-    default_focus_menu_link: WeakComponentLink<MatMenu>,
-    multi_activatable_menu_link: WeakComponentLink<MatMenu>,
+    shared_menu_link: WeakComponentLink<MatMenu>,
+    shared_menu_anchors: [NodeRef; 3],
+    menu_surface_link: WeakComponentLink<MatMenuSurface>,
+    context_action: String,
+    sort_order: SortOrder,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortOrder {
+    Name,
+    Date,
+    Size,
 }
 
 pub enum Msg {
-    ShowBasicMenu,
-    ShowCornerMenu,
-    ShowQuickMenu,
-    ShowFixedMenu,
-    ShowNonFixedMenu,
     ShowAbsoluteMenuNoAnchor,
-    ShowActivatableMenu,
-    ShowDefaultFoucsMenu,
-    ShowMultiActivatableMenu,
+    ShowSharedMenu(usize),
+    ShowMenuSurface,
+    CloseMenuSurface,
+    ContextAction(ContextMenuAction),
+    Sort(SortOrder),
 }
 
 impl Component for Menu {
@@ -36,29 +42,35 @@ impl Component for Menu {
     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
         Self {
             link,
-            basic_menu_link: WeakComponentLink::default(),
-            corner_menu_link: WeakComponentLink::default(),
-            quick_menu_link: WeakComponentLink::default(),
-            fixed_menu_link: WeakComponentLink::default(),
-            non_fixed_menu_link: WeakComponentLink::default(),
             absolute_menu_no_anchor_link: WeakComponentLink::default(),
-            activatable_menu_link: WeakComponentLink::default(),
-            default_focus_menu_link: WeakComponentLink::default(),
-            multi_activatable_menu_link: WeakComponentLink::default(),
+            shared_menu_link: WeakComponentLink::default(),
+            shared_menu_anchors: Default::default(),
+            menu_surface_link: WeakComponentLink::default(),
+            context_action: String::new(),
+            sort_order: SortOrder::Name,
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
-            Msg::ShowBasicMenu => self.basic_menu_link.show(),
-            Msg::ShowCornerMenu => self.corner_menu_link.show(),
-            Msg::ShowQuickMenu => self.quick_menu_link.show(),
-            Msg::ShowFixedMenu => self.fixed_menu_link.show(),
-            Msg::ShowNonFixedMenu => self.non_fixed_menu_link.show(),
             Msg::ShowAbsoluteMenuNoAnchor => self.absolute_menu_no_anchor_link.show(),
-            Msg::ShowActivatableMenu => self.activatable_menu_link.show(),
-            Msg::ShowDefaultFoucsMenu => self.default_focus_menu_link.show(),
-            Msg::ShowMultiActivatableMenu => self.multi_activatable_menu_link.show(),
+            Msg::ShowMenuSurface => self.menu_surface_link.show(),
+            Msg::CloseMenuSurface => self.menu_surface_link.close(),
+            Msg::ShowSharedMenu(index) => self
+                .shared_menu_link
+                .show_at(self.shared_menu_anchors[index].clone()),
+            Msg::Sort(order) => {
+                self.sort_order = order;
+                return true;
+            }
+            Msg::ContextAction(action) => {
+                self.context_action = format!(
+                    "Action {:?} on {}",
+                    action.index,
+                    action.item.unwrap_or_else(|| "nothing".to_string())
+                );
+                return true;
+            }
         }
         false
     }
@@ -69,32 +81,49 @@ impl Component for Menu {
 
     fn view(&self) -> Html {
         let basic_menu = with_raw_code!(basic_menu { html! {
-        <div style="position:relative;">
-            <span onclick=self.link.callback(|_| Msg::ShowBasicMenu)>
-                <MatButton raised=true label="Open Basic Menu"></MatButton>
-            </span>
-            <MatMenu menu_link=self.basic_menu_link.clone()>
-                <MatListItem>{"one"}</MatListItem>
-                <MatListItem>{"two"}</MatListItem>
-                <MatListItem>{"three"}</MatListItem>
-                <MatListItem disabled=true><div>{"four"}</div></MatListItem>
-                <li divider="true"></li>
-                <MatListItem>{"aaa"}</MatListItem>
-                <MatListItem>{"bbb"}</MatListItem>
-            </MatMenu>
-        </div>
+        <MatMenuButton label="Open Basic Menu" raised=true>
+            <MatListItem>{"one"}</MatListItem>
+            <MatListItem>{"two"}</MatListItem>
+            <MatListItem>{"three"}</MatListItem>
+            <MatListItem disabled=true><div>{"four"}</div></MatListItem>
+            <li divider="true"></li>
+            <MatListItem>{"aaa"}</MatListItem>
+            <MatListItem>{"bbb"}</MatListItem>
+        </MatMenuButton>
         }});
 
         let corner_menu = with_raw_code!(corner_menu { html! {
-        <div style="position:relative;">
-            <span onclick=self.link.callback(|_| Msg::ShowCornerMenu)>
-                <MatButton
-                    raised=true
-                    label="Open Menu in BottomRight Corner">
-                </MatButton>
-            </span>
-            // TODO allow user to enter corner value
-            <MatMenu corner=Corner::BottomRight menu_link=self.corner_menu_link.clone()>
+        // TODO allow user to enter corner value
+        <MatMenuButton
+            label="Open Menu in BottomRight Corner"
+            raised=true
+            corner=Corner::BottomRight
+        >
+            <MatListItem>{"one"}</MatListItem>
+            <MatListItem>{"two"}</MatListItem>
+            <MatListItem>{"three"}</MatListItem>
+            <MatListItem disabled=true><div>{"four"}</div></MatListItem>
+            <li divider="true"></li>
+            <MatListItem>{"aaa"}</MatListItem>
+            <MatListItem>{"bbb"}</MatListItem>
+        </MatMenuButton>
+        }});
+
+        let quick_menu = with_raw_code!(quick_menu { html! {
+        <MatMenuButton label="Open Quick Menu" raised=true quick=true>
+            <MatListItem>{"one"}</MatListItem>
+            <MatListItem>{"two"}</MatListItem>
+            <MatListItem>{"three"}</MatListItem>
+            <MatListItem disabled=true><div>{"four"}</div></MatListItem>
+            <li divider="true"></li>
+            <MatListItem>{"aaa"}</MatListItem>
+            <MatListItem>{"bbb"}</MatListItem>
+        </MatMenuButton>
+        }});
+
+        let scrollable = with_raw_code!(scrollable_menu { html! {
+        <div class="scrollable">
+            <MatMenuButton label="Open Fixed Menu" raised=true fixed=true>
                 <MatListItem>{"one"}</MatListItem>
                 <MatListItem>{"two"}</MatListItem>
                 <MatListItem>{"three"}</MatListItem>
@@ -102,16 +131,9 @@ impl Component for Menu {
                 <li divider="true"></li>
                 <MatListItem>{"aaa"}</MatListItem>
                 <MatListItem>{"bbb"}</MatListItem>
-            </MatMenu>
-        </div>
-        }});
+            </MatMenuButton>
 
-        let quick_menu = with_raw_code!(quick_menu { html! {
-        <div style="position:relative;">
-            <span onclick=self.link.callback(|_| Msg::ShowQuickMenu)>
-                <MatButton raised=true label="Open Quick Menu"></MatButton>
-            </span>
-            <MatMenu quick=true menu_link=self.quick_menu_link.clone()>
+            <MatMenuButton label="Open Non-Fixed Menu" raised=true>
                 <MatListItem>{"one"}</MatListItem>
                 <MatListItem>{"two"}</MatListItem>
                 <MatListItem>{"three"}</MatListItem>
@@ -119,41 +141,7 @@ impl Component for Menu {
                 <li divider="true"></li>
                 <MatListItem>{"aaa"}</MatListItem>
                 <MatListItem>{"bbb"}</MatListItem>
-            </MatMenu>
-        </div>
-        }});
-
-        let scrollable = with_raw_code!(scrollable_menu { html! {
-        <div class="scrollable">
-            <span>
-                <span onclick=self.link.callback(|_| Msg::ShowFixedMenu)>
-                    <MatButton raised=true label="Open Fixed Menu"></MatButton>
-                </span>
-                <MatMenu fixed=true menu_link=self.fixed_menu_link.clone()>
-                    <MatListItem>{"one"}</MatListItem>
-                    <MatListItem>{"two"}</MatListItem>
-                    <MatListItem>{"three"}</MatListItem>
-                    <MatListItem disabled=true><div>{"four"}</div></MatListItem>
-                    <li divider="true"></li>
-                    <MatListItem>{"aaa"}</MatListItem>
-                    <MatListItem>{"bbb"}</MatListItem>
-                </MatMenu>
-            </span>
-
-            <span style="position:relative;">
-                <span onclick=self.link.callback(|_| Msg::ShowNonFixedMenu)>
-                    <MatButton raised=true label="Open Non-Fixed Menu"></MatButton>
-                </span>
-                <MatMenu menu_link=self.non_fixed_menu_link.clone()>
-                    <MatListItem>{"one"}</MatListItem>
-                    <MatListItem>{"two"}</MatListItem>
-                    <MatListItem>{"three"}</MatListItem>
-                    <MatListItem disabled=true><div>{"four"}</div></MatListItem>
-                    <li divider="true"></li>
-                    <MatListItem>{"aaa"}</MatListItem>
-                    <MatListItem>{"bbb"}</MatListItem>
-                </MatMenu>
-            </span>
+            </MatMenuButton>
             <div>{"Open each menu and then scroll this div"}</div>
             <div class="spacer"></div>
         </div>
@@ -182,57 +170,134 @@ impl Component for Menu {
         }});
 
         let activatable_menu = with_raw_code!(activatable_menu { html! {
+        <MatMenuButton label="Open Activatable Menu" raised=true activatable=true>
+            <MatListItem>{"one"}</MatListItem>
+            <MatListItem>{"two"}</MatListItem>
+            <MatListItem>{"three"}</MatListItem>
+            <MatListItem disabled=true><div>{"four"}</div></MatListItem>
+            <li divider="true"></li>
+            <MatListItem>{"aaa"}</MatListItem>
+            <MatListItem>{"bbb"}</MatListItem>
+        </MatMenuButton>
+        }});
+
+        let multi_activatable_menu = with_raw_code!(multi_activatable_menu { html! {
+        <MatMenuButton
+            label="Open Multi (activatable) Menu"
+            raised=true
+            multi=true
+            activatable=true
+        >
+            <MatListItem selected=true activated=true>{"one"}</MatListItem>
+            <MatListItem>{"two"}</MatListItem>
+            <MatListItem selected=true activated=true>{"three"}</MatListItem>
+            <MatListItem disabled=true><div>{"four"}</div></MatListItem>
+            <li divider="true"></li>
+            <MatListItem>{"aaa"}</MatListItem>
+            <MatListItem>{"bbb"}</MatListItem>
+        </MatMenuButton>
+        }});
+
+        let default_focus_menu = with_raw_code!(default_focus_menu { html! {
+        <MatMenuButton
+            label="Open Menu With Default Focus with focus of LastItem"
+            raised=true
+            default_focus=DefaultFocusState::LastItem
+        >
+            <MatListItem>{"one"}</MatListItem>
+            <MatListItem>{"two"}</MatListItem>
+            <MatListItem>{"three"}</MatListItem>
+            <MatListItem disabled=true><div>{"four"}</div></MatListItem>
+            <li divider="true"></li>
+            <MatListItem>{"aaa"}</MatListItem>
+            <MatListItem>{"bbb"}</MatListItem>
+        </MatMenuButton>
+        }});
+
+        let nested_menu = with_raw_code!(nested_menu { html! {
+        <MatMenuButton label="Open Nested Menu" raised=true>
+            <MatListItem>{"New"}</MatListItem>
+            <MatSubMenu label="Open recent">
+            <MatListItem>{"notes.txt"}</MatListItem>
+                <MatSubMenu label="More">
+                    <MatListItem>{"photo.png"}</MatListItem>
+                    <MatListItem>{"song.mp3"}</MatListItem>
+                </MatSubMenu>
+            </MatSubMenu>
+            <MatListItem>{"Save"}</MatListItem>
+        </MatMenuButton>
+        }});
+
+        let shared_menu = with_raw_code!(shared_menu { html! {
         <div style="position:relative;">
-            <span onclick=self.link.callback(|_| Msg::ShowActivatableMenu)>
-                <MatButton raised=true label="Open Activatable Menu"></MatButton>
-            </span>
-            <MatMenu activatable=true menu_link=self.activatable_menu_link.clone()>
+            { for self.shared_menu_anchors.iter().enumerate().map(|(index, anchor)| html! {
+                <span ref=anchor.clone() onclick=self.link.callback(move |_| Msg::ShowSharedMenu(index))>
+                    <MatButton raised=true label=format!("Trigger {}", index)></MatButton>
+                </span>
+            }) }
+            <MatMenu fixed=true corner=Corner::BottomStart menu_link=self.shared_menu_link.clone()>
                 <MatListItem>{"one"}</MatListItem>
                 <MatListItem>{"two"}</MatListItem>
                 <MatListItem>{"three"}</MatListItem>
-                <MatListItem disabled=true><div>{"four"}</div></MatListItem>
-                <li divider="true"></li>
-                <MatListItem>{"aaa"}</MatListItem>
-                <MatListItem>{"bbb"}</MatListItem>
             </MatMenu>
         </div>
         }});
 
-        let multi_activatable_menu = with_raw_code!(multi_activatable_menu { html! {
-        <div style="position:relative;">
-            <span onclick=self.link.callback(|_| Msg::ShowMultiActivatableMenu)>
-                <MatButton raised=true label="Open Multi (activatable) Menu"></MatButton>
-            </span>
-            <MatMenu multi=true activatable=true menu_link=self.multi_activatable_menu_link.clone()>
-                <MatListItem selected=true activated=true>{"one"}</MatListItem>
-                <MatListItem>{"two"}</MatListItem>
-                <MatListItem selected=true activated=true>{"three"}</MatListItem>
-                <MatListItem disabled=true><div>{"four"}</div></MatListItem>
-                <li divider="true"></li>
-                <MatListItem>{"aaa"}</MatListItem>
-                <MatListItem>{"bbb"}</MatListItem>
-            </MatMenu>
+        let menu_button = with_raw_code!(menu_button { html! {
+        <div>
+            <MatMenuButton<SortOrder>
+                label="Sort by"
+                icon=Cow::from("sort")
+                outlined=true
+                items=vec![
+                    ListOption::new(SortOrder::Name, "Name"),
+                    ListOption::new(SortOrder::Date, "Date"),
+                    ListOption::new(SortOrder::Size, "Size"),
+                ]
+                onaction=self.link.callback(Msg::Sort)
+            />
+            <MatMenuButton<SortOrder>
+                label="More sort options"
+                icon=Cow::from("more_vert")
+                icon_button=true
+                items=vec![ListOption::new(SortOrder::Size, "Largest first")]
+                onaction=self.link.callback(Msg::Sort)
+            />
+            <div>{format!("Sorted by {:?}", self.sort_order)}</div>
         </div>
         }});
 
-        let default_focus_menu = with_raw_code!(default_focus_menu { html! {
+        let menu_surface = with_raw_code!(menu_surface { html! {
         <div style="position:relative;">
-            <span onclick=self.link.callback(|_| Msg::ShowDefaultFoucsMenu)>
-                <MatButton
-                    raised=true
-                    label="Open Menu With Default Focus with focus of LastItem">
-                </MatButton>
+            <span onclick=self.link.callback(|_| Msg::ShowMenuSurface)>
+                <MatButton raised=true label="Open Menu Surface"></MatButton>
             </span>
-            // TODO allow user to enter corner value
-            <MatMenu default_focus=DefaultFocusState::LastItem menu_link=self.default_focus_menu_link.clone()>
-                <MatListItem>{"one"}</MatListItem>
-                <MatListItem>{"two"}</MatListItem>
-                <MatListItem>{"three"}</MatListItem>
-                <MatListItem disabled=true><div>{"four"}</div></MatListItem>
-                <li divider="true"></li>
-                <MatListItem>{"aaa"}</MatListItem>
-                <MatListItem>{"bbb"}</MatListItem>
-            </MatMenu>
+            <MatMenuSurface corner=Corner::BottomStart menu_surface_link=self.menu_surface_link.clone()>
+                <div style="padding: 16px;">
+                    <p>{"Any content can go in a menu surface"}</p>
+                    <span onclick=self.link.callback(|_| Msg::CloseMenuSurface)>
+                        <MatButton label="Close"></MatButton>
+                    </span>
+                </div>
+            </MatMenuSurface>
+        </div>
+        }});
+
+        let context_menu = with_raw_code!(context_menu { html! {
+        <div>
+            <MatContextMenu
+                menu=html! {<>
+                <MatListItem>{"Copy"}</MatListItem>
+                <MatListItem>{"Rename"}</MatListItem>
+                <MatListItem>{"Delete"}</MatListItem>
+                </>}
+                onaction=self.link.callback(Msg::ContextAction)
+            >
+                <div>{"Right-click or long-press a file"}</div>
+                <div data-context-item="notes.txt">{"notes.txt"}</div>
+                <div data-context-item="photo.png">{"photo.png"}</div>
+            </MatContextMenu>
+            <div>{&self.context_action}</div>
         </div>
         }});
 
@@ -253,6 +318,16 @@ impl Component for Menu {
             <Codeblock code_and_html=multi_activatable_menu title="Multi (activatable) Menu" />
 
             <Codeblock code_and_html=default_focus_menu title="Menu With Default Focus" />
+
+            <Codeblock code_and_html=nested_menu title="Nested Menu" />
+
+            <Codeblock code_and_html=shared_menu title="Menu Shared By Several Anchors" />
+
+            <Codeblock code_and_html=menu_button title="Menu Button" />
+
+            <Codeblock code_and_html=menu_surface title="Menu Surface" />
+
+            <Codeblock code_and_html=context_menu title="Context Menu" />
         </main>
         }
     }
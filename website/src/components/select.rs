@@ -1,18 +1,22 @@
 use crate::components::Codeblock;
 use crate::with_raw_code;
 use yew::prelude::*;
-use yew_material::list::GraphicType;
-use yew_material::{MatButton, MatListItem, MatSelect, WeakComponentLink};
+use yew_material::list::{GraphicType, ListOption};
+use yew_material::{MatButton, MatListItem, MatMultiSelect, MatSelect, WeakComponentLink};
 
 pub struct Select {
     link: ComponentLink<Self>,
     natural_menu_width: bool,
     select_link: WeakComponentLink<MatSelect>,
+    selected_option: Option<u8>,
+    selected_options: Vec<u8>,
 }
 
 pub enum Msg {
     ToggleNaturalMenuWidth,
     SelectIndex2,
+    OptionSelected(Option<u8>),
+    OptionsSelected(Vec<u8>),
 }
 
 impl Component for Select {
@@ -25,6 +29,8 @@ impl Component for Select {
             link,
             natural_menu_width: true,
             select_link,
+            selected_option: None,
+            selected_options: vec![],
         }
     }
 
@@ -38,6 +44,14 @@ impl Component for Select {
                 self.select_link.select(2);
                 false
             }
+            Msg::OptionSelected(value) => {
+                self.selected_option = value;
+                true
+            }
+            Msg::OptionsSelected(values) => {
+                self.selected_options = values;
+                true
+            }
         }
     }
 
@@ -148,6 +162,41 @@ impl Component for Select {
         </section>
         }});
 
+        let options = with_raw_code!(options { html! {
+        <section>
+            <MatSelect<u8>
+                label="Options"
+                options=(0..4).map(|i| ListOption::new(i, format!("Option {}", i))).collect::<Vec<_>>()
+                selected=self.selected_option
+                onchange=self.link.callback(Msg::OptionSelected)
+            />
+            <span>{ format!("Selected: {:?}", self.selected_option) }</span>
+        </section>
+        }});
+
+        let searchable = with_raw_code!(searchable { html! {
+        <section>
+            <MatSelect<u8>
+                label="Searchable"
+                searchable=true
+                options=(0..100).map(|i| ListOption::new(i, format!("Option {}", i))).collect::<Vec<_>>()
+            />
+        </section>
+        }});
+
+        let multi = with_raw_code!(multi { html! {
+        <section>
+            <MatMultiSelect<u8>
+                label="Multi"
+                outlined=true
+                options=(0..4).map(|i| ListOption::new(i, format!("Option {}", i))).collect::<Vec<_>>()
+                selected=self.selected_options.clone()
+                onchange=self.link.callback(Msg::OptionsSelected)
+            />
+            <span>{ format!("Selected: {:?}", self.selected_options) }</span>
+        </section>
+        }});
+
         html! {
         <main class="list-demo">
 
@@ -167,6 +216,12 @@ impl Component for Select {
 
             <Codeblock title="Natural width" code_and_html=natural_width />
 
+            <Codeblock title="Options" code_and_html=options />
+
+            <Codeblock title="Searchable" code_and_html=searchable />
+
+            <Codeblock title="Multi select" code_and_html=multi />
+
         </main>}
     }
 }
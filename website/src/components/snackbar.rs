@@ -1,23 +1,32 @@
 use crate::components::Codeblock;
 use crate::with_raw_code;
+use std::borrow::Cow;
 use yew::prelude::*;
 use yew::services::ConsoleService;
-use yew_material::{MatButton, MatIconButton, MatSnackbar, WeakComponentLink};
+use yew_material::snackbar::{PendingOperation, SnackbarCloseReason};
+use yew_material::snackbar_queue::{self, Notification, Priority};
+use yew_material::{MatButton, MatIconButton, MatSnackbar, MatSnackbarHost, WeakComponentLink};
 
 pub struct Snackbar {
     link: ComponentLink<Self>,
     default_link: WeakComponentLink<MatSnackbar>,
     leading_link: WeakComponentLink<MatSnackbar>,
     stacked_link: WeakComponentLink<MatSnackbar>,
+    items: usize,
 }
 
 pub enum Msg {
     OpenDefault,
     OpenLeading,
     OpenStacked,
-    DefaultClosed(Option<String>),
-    LeadingClosed(Option<String>),
-    StackedClosed(Option<String>),
+    Notify,
+    NotifyWithAction,
+    NotifyUrgent,
+    Delete,
+    RestoreItem,
+    DefaultClosed(Option<SnackbarCloseReason>),
+    LeadingClosed(Option<SnackbarCloseReason>),
+    StackedClosed(Option<SnackbarCloseReason>),
 }
 
 impl Component for Snackbar {
@@ -30,6 +39,7 @@ impl Component for Snackbar {
             default_link: WeakComponentLink::default(),
             leading_link: WeakComponentLink::default(),
             stacked_link: WeakComponentLink::default(),
+            items: 5,
         }
     }
 
@@ -44,6 +54,32 @@ impl Component for Snackbar {
             Msg::OpenStacked => {
                 self.stacked_link.show();
             }
+            Msg::Notify => snackbar_queue::notify("Message sent"),
+            Msg::NotifyWithAction => snackbar_queue::notify_with_action(
+                "Message archived",
+                "Undo",
+                Callback::from(|_| ConsoleService::log("archiving undone")),
+            ),
+            Msg::NotifyUrgent => snackbar_queue::enqueue(
+                Notification::new("Connection lost").priority(Priority::High),
+            ),
+            Msg::Delete => {
+                // the item is hidden right away, and restored if undone
+                self.items -= 1;
+                snackbar_queue::notify_with_undo(
+                    "Item deleted",
+                    "Undo",
+                    PendingOperation::new(
+                        Callback::from(|_| ConsoleService::log("item deleted")),
+                        self.link.callback(|_| Msg::RestoreItem),
+                    ),
+                );
+                return true;
+            }
+            Msg::RestoreItem => {
+                self.items += 1;
+                return true;
+            }
             Msg::DefaultClosed(reason) => {
                 ConsoleService::log(&format!("default closed with reason {:?}", reason))
             }
@@ -68,15 +104,8 @@ impl Component for Snackbar {
                 <MatButton label="Open default snackbar" raised=true  />
              </span>
             <MatSnackbar label_text="Can't send photo. Retry in 5 seconds." snackbar_link=self.default_link.clone()
-                onclosed=self.link.callback(Msg::DefaultClosed)>
-                <span slot="action">
-                    <MatButton label="RETRY" />
-                </span>
-
-                <span class="snackbar-dismiss-slot" slot="dismiss">
-                    <MatIconButton icon="close" />
-                </span>
-            </MatSnackbar>
+                action_label=Cow::from("RETRY") dismissible=true
+                onclosed=self.link.callback(Msg::DefaultClosed) />
         </section>
         }});
 
@@ -116,12 +145,40 @@ impl Component for Snackbar {
         </section>
         }});
 
+        let queue = with_raw_code!(queue { html! {
+        <section style="margin: 1em 0;">
+            <span onclick=self.link.callback(|_| Msg::Notify)>
+                <MatButton label="Notify" raised=true />
+            </span>
+            <span onclick=self.link.callback(|_| Msg::NotifyWithAction)>
+                <MatButton label="Notify with action" raised=true />
+            </span>
+            <span onclick=self.link.callback(|_| Msg::NotifyUrgent)>
+                <MatButton label="Notify with high priority" raised=true />
+            </span>
+            <MatSnackbarHost />
+        </section>
+        }});
+
+        let undo = with_raw_code!(undo { html! {
+        <section style="margin: 1em 0;">
+            <p>{ format!("{} items", self.items) }</p>
+            <span onclick=self.link.callback(|_| Msg::Delete)>
+                <MatButton label="Delete an item" raised=true disabled=self.items == 0 />
+            </span>
+        </section>
+        }});
+
         html! {<>
             <Codeblock title="Default" code_and_html=default />
 
             <Codeblock title="Leading" code_and_html=leading />
 
             <Codeblock title="Stacked" code_and_html=stacked />
+
+            <Codeblock title="Queue" code_and_html=queue />
+
+            <Codeblock title="Undo" code_and_html=undo />
         </>}
     }
 }
@@ -8,7 +8,7 @@ use crate::components::{
 };
 use yew::prelude::*;
 use yew_material::{
-    drawer::{MatDrawerAppContent, MatDrawerTitle},
+    drawer::{DrawerType, MatDrawerAppContent, MatDrawerTitle},
     top_app_bar_fixed::{MatTopAppBarActionItems, MatTopAppBarNavigationIcon, MatTopAppBarTitle},
     MatButton, MatDrawer, MatIconButton, MatList, MatListItem, MatTopAppBarFixed,
 };
@@ -155,7 +155,7 @@ impl Component for App {
         };
 
         html! { <>
-        <MatDrawer open=self.drawer_state drawer_type="dismissible"
+        <MatDrawer open=self.drawer_state drawer_type=DrawerType::Dismissible
             onopened=self.link.callback(|_| Msg::Opened)
             onclosed=self.link.callback(|_| Msg::Closed)>
 
@@ -0,0 +1,9 @@
+import{a8 as t,_ as e,q as o,p as i,o as n,i as s,ac as r,B as d,ad as l,h as a,c,r as u,j as h,J as m,K as p,b as f,d as y}from"./core.js";const A={TOP_LEFT:t.TOP_LEFT,TOP_RIGHT:t.TOP_RIGHT,BOTTOM_LEFT:t.BOTTOM_LEFT,BOTTOM_RIGHT:t.BOTTOM_RIGHT,TOP_START:t.TOP_START,TOP_END:t.TOP_END,BOTTOM_START:t.BOTTOM_START,BOTTOM_END:t.BOTTOM_END};class w extends d{constructor(){super(...arguments),this.mdcFoundationClass=l,this.absolute=!1,this.fullwidth=!1,this.fixed=!1,this.x=null,this.y=null,this.quick=!1,this.open=!1,this.bitwiseCorner=t.TOP_START,this.previousMenuCorner=null,this.menuCorner="START",this.corner="TOP_START",this.styleTop="",this.styleLeft="",this.styleRight="",this.styleBottom="",this.styleMaxHeight="",this.styleTransformOrigin="",this.anchor=null,this.previouslyFocused=null,this.previousAnchor=null,this.onBodyClickBound=()=>{}}render(){const t={"mdc-menu-surface--fixed":this.fixed,"mdc-menu-surface--fullwidth":this.fullwidth},e={top:this.styleTop,left:this.styleLeft,right:this.styleRight,bottom:this.styleBottom,"max-height":this.styleMaxHeight,"transform-origin":this.styleTransformOrigin};return a`
+      <div
+          class="mdc-menu-surface ${c(t)}"
+          style="${u(e)}"
+          @keydown=${this.onKeydown}
+          @opened=${this.registerBodyClick}
+          @closed=${this.deregisterBodyClick}>
+        <slot></slot>
+      </div>`}createAdapter(){return Object.assign(Object.assign({},h(this.mdcRoot)),{hasAnchor:()=>!!this.anchor,notifyClose:()=>{const t=new CustomEvent("closed",{bubbles:!0,composed:!0});this.open=!1,this.mdcRoot.dispatchEvent(t)},notifyOpen:()=>{const t=new CustomEvent("opened",{bubbles:!0,composed:!0});this.open=!0,this.mdcRoot.dispatchEvent(t)},isElementInContainer:()=>!1,isRtl:()=>!!this.mdcRoot&&"rtl"===getComputedStyle(this.mdcRoot).direction,setTransformOrigin:t=>{this.mdcRoot&&(this.styleTransformOrigin=t)},isFocused:()=>m(this),saveFocus:()=>{const t=p(),e=t.length;e||(this.previouslyFocused=null),this.previouslyFocused=t[e-1]},restoreFocus:()=>{this.previouslyFocused&&"focus"in this.previouslyFocused&&this.previouslyFocused.focus()},getInnerDimensions:()=>{const t=this.mdcRoot;return t?{width:t.offsetWidth,height:t.offsetHeight}:{width:0,height:0}},getAnchorDimensions:()=>{const t=this.anchor;return t?t.getBoundingClientRect():null},getBodyDimensions:()=>({width:document.body.clientWidth,height:document.body.clientHeight}),getWindowDimensions:()=>({width:window.innerWidth,height:window.innerHeight}),getWindowScroll:()=>({x:window.pageXOffset,y:window.pageYOffset}),setPosition:t=>{this.mdcRoot&&(this.styleLeft="left"in t?t.left+"px":"",this.styleRight="right"in t?t.right+"px":"",this.styleTop="top"in t?t.top+"px":"",this.styleBottom="bottom"in t?t.bottom+"px":"")},setMaxHeight:async t=>{this.mdcRoot&&(this.styleMaxHeight=t,await this.updateComplete,this.styleMaxHeight=`var(--mdc-menu-max-height, ${t})`)}})}onKeydown(t){this.mdcFoundation&&this.mdcFoundation.handleKeydown(t)}onBodyClick(t){-1===t.composedPath().indexOf(this)&&this.close()}registerBodyClick(){this.onBodyClickBound=this.onBodyClick.bind(this),document.body.addEventListener("click",this.onBodyClickBound,{passive:!0,capture:!0})}deregisterBodyClick(){document.body.removeEventListener("click",this.onBodyClickBound,{capture:!0})}close(){this.open=!1}show(){this.open=!0}}e([o(".mdc-menu-surface")],w.prototype,"mdcRoot",void 0),e([o("slot")],w.prototype,"slotElement",void 0),e([i({type:Boolean}),n((function(t){this.mdcFoundation&&!this.fixed&&this.mdcFoundation.setIsHoisted(t)}))],w.prototype,"absolute",void 0),e([i({type:Boolean})],w.prototype,"fullwidth",void 0),e([i({type:Boolean}),n((function(t){this.mdcFoundation&&!this.absolute&&this.mdcFoundation.setIsHoisted(t)}))],w.prototype,"fixed",void 0),e([i({type:Number}),n((function(t){this.mdcFoundation&&null!==this.y&&null!==t&&(this.mdcFoundation.setAbsolutePosition(t,this.y),this.mdcFoundation.setAnchorMargin({left:t,top:this.y,right:-t,bottom:this.y}))}))],w.prototype,"x",void 0),e([i({type:Number}),n((function(t){this.mdcFoundation&&null!==this.x&&null!==t&&(this.mdcFoundation.setAbsolutePosition(this.x,t),this.mdcFoundation.setAnchorMargin({left:this.x,top:t,right:-this.x,bottom:t}))}))],w.prototype,"y",void 0),e([i({type:Boolean}),n((function(t){this.mdcFoundation&&this.mdcFoundation.setQuickOpen(t)}))],w.prototype,"quick",void 0),e([i({type:Boolean,reflect:!0}),n((function(t,e){this.mdcFoundation&&(t?this.mdcFoundation.open():void 0!==e&&this.mdcFoundation.close())}))],w.prototype,"open",void 0),e([s(),n((function(t){this.mdcFoundation&&this.mdcFoundation.setAnchorCorner(t)}))],w.prototype,"bitwiseCorner",void 0),e([i({type:String}),n((function(t){if(this.mdcFoundation){const e="START"===t||"END"===t,o=null===this.previousMenuCorner,i=!o&&t!==this.previousMenuCorner,n=o&&"END"===t;e&&(i||n)&&(this.bitwiseCorner=this.bitwiseCorner^r.RIGHT,this.mdcFoundation.flipCornerHorizontally(),this.previousMenuCorner=t)}}))],w.prototype,"menuCorner",void 0),e([i({type:String}),n((function(t){if(this.mdcFoundation&&t){let e=A[t];"END"===this.menuCorner&&(e^=r.RIGHT),this.bitwiseCorner=e}}))],w.prototype,"corner",void 0),e([s()],w.prototype,"styleTop",void 0),e([s()],w.prototype,"styleLeft",void 0),e([s()],w.prototype,"styleRight",void 0),e([s()],w.prototype,"styleBottom",void 0),e([s()],w.prototype,"styleMaxHeight",void 0),e([s()],w.prototype,"styleTransformOrigin",void 0);const C=f`.mdc-menu-surface{display:none;position:absolute;box-sizing:border-box;max-width:calc(100vw - 32px);max-height:calc(100vh - 32px);margin:0;padding:0;transform:scale(1);transform-origin:top left;opacity:0;overflow:auto;will-change:transform,opacity;z-index:8;transition:opacity .03s linear,transform .12s cubic-bezier(0, 0, 0.2, 1),height 250ms cubic-bezier(0, 0, 0.2, 1);box-shadow:0px 5px 5px -3px rgba(0, 0, 0, 0.2),0px 8px 10px 1px rgba(0, 0, 0, 0.14),0px 3px 14px 2px rgba(0,0,0,.12);background-color:#fff;background-color:var(--mdc-theme-surface, #fff);color:#000;color:var(--mdc-theme-on-surface, #000);border-radius:4px;border-radius:var(--mdc-shape-medium, 4px);transform-origin-left:top left;transform-origin-right:top right}.mdc-menu-surface:focus{outline:none}.mdc-menu-surface--open{display:inline-block;transform:scale(1);opacity:1}.mdc-menu-surface--animating-open{display:inline-block;transform:scale(0.8);opacity:0}.mdc-menu-surface--animating-closed{display:inline-block;opacity:0;transition:opacity .075s linear}[dir=rtl] .mdc-menu-surface,.mdc-menu-surface[dir=rtl]{transform-origin-left:top right;transform-origin-right:top left}.mdc-menu-surface--anchor{position:relative;overflow:visible}.mdc-menu-surface--fixed{position:fixed}.mdc-menu-surface--fullwidth{width:100%}:host(:not([open])){display:none}.mdc-menu-surface{z-index:8;z-index:var(--mdc-menu-z-index, 8);max-height:calc(100vh - 32px);max-height:var(--mdc-menu-max-height, calc(100vh - 32px))}`;let _=class extends w{};_.styles=C,_=e([y("mwc-menu-surface")],_);export{_ as MenuSurface};
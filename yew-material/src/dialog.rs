@@ -1,12 +1,20 @@
+mod before_close;
 mod dialog_action;
+mod stack;
 
+pub use before_close::BeforeCloseEvent;
 pub use dialog_action::*;
 
+use crate::utils::update_complete;
 use crate::{bool_to_option, event_details_into, WeakComponentLink};
-use gloo::events::EventListener;
+use gloo::events::{EventListener, EventListenerOptions};
 use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
 use wasm_bindgen::prelude::*;
-use web_sys::{Element, Node};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{Element, EventTarget, HtmlElement, Node};
 use yew::prelude::*;
 
 #[wasm_bindgen(module = "/build/mwc-dialog.js")]
@@ -41,13 +49,20 @@ loader_hack!(Dialog);
 ///
 /// In order to pass actions, [`MatDialogAction`] component should be
 /// used.
-pub struct MatDialog {
-    props: DialogProps,
+///
+/// Actions are values of `A`, which is converted to and from the
+/// `dialogAction` strings with its `Display` and `FromStr` implementations.
+/// See [`DialogAction`] for how the actions reported on closing are read.
+pub struct MatDialog<A: FromStr + Display + Clone + 'static = String> {
+    props: DialogProps<A>,
+    link: ComponentLink<Self>,
     node_ref: NodeRef,
     opening_listener: Option<EventListener>,
     opened_listener: Option<EventListener>,
     closing_listener: Option<EventListener>,
     closed_listener: Option<EventListener>,
+    /// Listeners giving `onbeforeclose` a chance to keep the dialog open
+    before_close_listeners: Vec<EventListener>,
 }
 
 /// Props for [`MatDialog`]
@@ -57,7 +72,7 @@ pub struct MatDialog {
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/master/packages/dialog#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/master/packages/dialog#events)
 #[derive(Properties, Clone)]
-pub struct DialogProps {
+pub struct DialogProps<A: FromStr + Display + Clone + 'static> {
     #[prop_or_default]
     pub classes: Classes,
     #[prop_or_default]
@@ -68,12 +83,18 @@ pub struct DialogProps {
     pub stacked: bool,
     #[prop_or_default]
     pub heading: Option<Cow<'static, str>>,
-    #[prop_or_default]
-    pub scrim_click_action: Option<Cow<'static, str>>,
-    #[prop_or_default]
-    pub escape_key_action: Option<Cow<'static, str>>,
-    #[prop_or_default]
-    pub default_action: Option<Cow<'static, str>>,
+    /// Action the dialog closes with when the scrim is clicked. `None` keeps
+    /// the dialog open.
+    #[prop_or(Some(DialogAction::Scrim))]
+    pub scrim_click_action: Option<DialogAction<A>>,
+    /// Action the dialog closes with when `Escape` is pressed. `None` keeps
+    /// the dialog open.
+    #[prop_or(Some(DialogAction::Escape))]
+    pub escape_key_action: Option<DialogAction<A>>,
+    /// Action the dialog closes with when `open` is set to `false` or `close()`
+    /// is called
+    #[prop_or(DialogAction::Close)]
+    pub default_action: DialogAction<A>,
     #[prop_or_default]
     pub action_attribute: Option<Cow<'static, str>>,
     #[prop_or_default]
@@ -92,43 +113,63 @@ pub struct DialogProps {
     ///
     /// See events docs to learn more.
     #[prop_or_default]
-    pub onclosing: Callback<String>,
+    pub onclosing: Callback<DialogAction<A>>,
+    /// Emitted before the dialog closes because an action was clicked, the
+    /// scrim was clicked or `Escape` was pressed.
+    ///
+    /// Calling [`BeforeCloseEvent::prevent_default`] keeps the dialog open.
+    /// Closing the dialog by setting `open` to `false` or calling `close()`
+    /// can't be prevented.
+    #[prop_or_default]
+    pub onbeforeclose: Callback<BeforeCloseEvent<A>>,
     /// Binds to `closed` event on `mwc-dialog`
     ///
     /// See events docs to learn more.
     #[prop_or_default]
-    pub onclosed: Callback<String>,
+    pub onclosed: Callback<DialogAction<A>>,
     /// [`WeakComponentLink`] for `MatDialog` which provides the following
     /// methods:
     /// - ```focus(&self)```
     /// - ```blur(&self)```
     /// - ```show(&self)```
     /// - ```close(&self)```
+    /// - ```show_stacked(&self)```
+    /// - ```show_queued(&self)```
     ///
     /// See [`WeakComponentLink`] documentation for more information
     #[prop_or_default]
-    pub dialog_link: WeakComponentLink<MatDialog>,
+    pub dialog_link: WeakComponentLink<MatDialog<A>>,
     pub children: Children,
 }
 
-impl Component for MatDialog {
-    type Message = ();
-    type Properties = DialogProps;
+pub enum Msg {
+    /// The shadow root of `mwc-dialog` has been rendered
+    Upgraded,
+}
+
+impl<A: FromStr + Display + Clone + 'static> Component for MatDialog<A> {
+    type Message = Msg;
+    type Properties = DialogProps<A>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        props.dialog_link.borrow_mut().replace(link);
+        props.dialog_link.borrow_mut().replace(link.clone());
         Dialog::ensure_loaded();
         Self {
             props,
+            link,
             node_ref: NodeRef::default(),
             opening_listener: None,
             opened_listener: None,
             closing_listener: None,
             closed_listener: None,
+            before_close_listeners: Vec::new(),
         }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Upgraded => self.listen_before_close(),
+        }
         false
     }
 
@@ -145,9 +186,9 @@ impl Component for MatDialog {
             hideActions=bool_to_option(self.props.hide_action)
             stacked=bool_to_option(self.props.stacked)
             heading=self.props.heading.clone()
-            scrimClickAction=self.props.scrim_click_action.clone()
-            escapeKeyAction=self.props.escape_key_action.clone()
-            defaultAction=self.props.default_action.clone()
+            scrimClickAction=action_attribute(self.props.scrim_click_action.as_ref())
+            escapeKeyAction=action_attribute(self.props.escape_key_action.as_ref())
+            defaultAction=self.props.default_action.to_string()
             actionAttribute=self.props.action_attribute.clone()
             initialFocusAttribute=self.props.initial_focus_attribute.clone()
             ref=self.node_ref.clone()>
@@ -156,39 +197,134 @@ impl Component for MatDialog {
                 }
     }
 
-    fn rendered(&mut self, _first_render: bool) {
+    fn rendered(&mut self, first_render: bool) {
         let element = self.node_ref.cast::<Element>().unwrap();
+        if first_render {
+            // the elements clicks are listened to on are in the shadow root
+            let element = element.clone();
+            let link = self.link.clone();
+            spawn_local(async move {
+                update_complete(&element).await;
+                link.send_message(Msg::Upgraded);
+            });
+        }
+
         if self.opening_listener.is_none() {
             let onopening = self.props.onopening.clone();
-            self.opening_listener = Some(EventListener::new(&element, "opening", move |_| {
-                onopening.emit(())
+            let dialog = element.clone();
+            self.opening_listener = Some(EventListener::new(&element, "opening", move |event| {
+                if is_dialog_event(event, &dialog) {
+                    onopening.emit(())
+                }
             }));
         }
 
         if self.opened_listener.is_none() {
             let onopened = self.props.onopened.clone();
-            self.opened_listener = Some(EventListener::new(&element, "opened", move |_| {
-                onopened.emit(())
+            let dialog = element.clone();
+            self.opened_listener = Some(EventListener::new(&element, "opened", move |event| {
+                if is_dialog_event(event, &dialog) {
+                    onopened.emit(())
+                }
             }));
         }
 
         if self.closing_listener.is_none() {
             let onclosing = self.props.onclosing.clone();
+            let dialog = element.clone();
             self.closing_listener = Some(EventListener::new(&element, "closing", move |event| {
-                onclosing.emit(action_from_event(event))
+                if is_dialog_event(event, &dialog) {
+                    onclosing.emit(action_from_event(event))
+                }
             }));
         }
 
         if self.closed_listener.is_none() {
             let onclosed = self.props.onclosed.clone();
+            let dialog = element.clone();
             self.closed_listener = Some(EventListener::new(&element, "closed", move |event| {
-                onclosed.emit(action_from_event(event))
+                if is_dialog_event(event, &dialog) {
+                    onclosed.emit(action_from_event(event))
+                }
             }));
         }
     }
 }
 
-impl WeakComponentLink<MatDialog> {
+impl<A: FromStr + Display + Clone + 'static> MatDialog<A> {
+    /// Adds the listeners emitting `onbeforeclose`.
+    ///
+    /// `mwc-dialog` closes when a click reaches `.mdc-dialog`, so clicks are
+    /// stopped on its children, before they do. It listens to `keydown` on
+    /// `document`, so `Escape` is stopped on `window`.
+    fn listen_before_close(&mut self) {
+        let element = self.node_ref.cast::<Element>().unwrap();
+        let root = match element.shadow_root() {
+            Some(root) => root,
+            None => return,
+        };
+
+        if let Ok(Some(container)) = root.query_selector(".mdc-dialog__container") {
+            let element = element.clone();
+            let onbeforeclose = self.props.onbeforeclose.clone();
+            self.before_close_listeners.push(EventListener::new(
+                &container,
+                "click",
+                move |event| {
+                    let attribute = property_string(&element, "actionAttribute");
+                    let action = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Element>().ok())
+                        .and_then(|target| target.closest(&format!("[{}]", attribute)).ok())
+                        .flatten()
+                        .and_then(|target| target.get_attribute(&attribute));
+                    if let Some(action) = action {
+                        if close_prevented(&onbeforeclose, &action) {
+                            event.stop_propagation();
+                        }
+                    }
+                },
+            ));
+        }
+
+        if let Ok(Some(scrim)) = root.query_selector(".mdc-dialog__scrim") {
+            let element = element.clone();
+            let onbeforeclose = self.props.onbeforeclose.clone();
+            self.before_close_listeners
+                .push(EventListener::new(&scrim, "click", move |event| {
+                    let action = property_string(&element, "scrimClickAction");
+                    if close_prevented(&onbeforeclose, &action) {
+                        event.stop_propagation();
+                    }
+                }));
+        }
+
+        let onbeforeclose = self.props.onbeforeclose.clone();
+        let window = web_sys::window().unwrap();
+        let options = EventListenerOptions::run_in_capture_phase();
+        self.before_close_listeners
+            .push(EventListener::new_with_options(
+                &window,
+                "keydown",
+                options,
+                move |event| {
+                    if event.unchecked_ref::<KeyboardEvent>().key() != "Escape" {
+                        return;
+                    }
+                    let open = js_sys::Reflect::get(&element, &JsValue::from("open"))
+                        .ok()
+                        .and_then(|open| open.as_bool())
+                        .unwrap_or(false);
+                    let action = property_string(&element, "escapeKeyAction");
+                    if open && close_prevented(&onbeforeclose, &action) {
+                        event.stop_propagation();
+                    }
+                },
+            ));
+    }
+}
+
+impl<A: FromStr + Display + Clone + 'static> WeakComponentLink<MatDialog<A>> {
     pub fn focus(&self) {
         (*self.borrow().as_ref().unwrap().get_component().unwrap())
             .node_ref
@@ -220,6 +356,28 @@ impl WeakComponentLink<MatDialog> {
             .unwrap()
             .close()
     }
+
+    /// Shows the dialog on top of the dialogs opened with `show_stacked` or
+    /// `show_queued` which are still open.
+    ///
+    /// Only the top dialog closes when `Escape` is pressed, and when it closes
+    /// the focus goes back to the element which was focused before it opened.
+    pub fn show_stacked(&self) {
+        stack::push(self.element())
+    }
+
+    /// Like `show_stacked`, but waits for the dialogs opened with
+    /// `show_stacked` or `show_queued` to close before showing the dialog.
+    pub fn show_queued(&self) {
+        stack::enqueue(self.element())
+    }
+
+    fn element(&self) -> HtmlElement {
+        (*self.borrow().as_ref().unwrap().get_component().unwrap())
+            .node_ref
+            .cast::<HtmlElement>()
+            .unwrap()
+    }
 }
 
 #[wasm_bindgen]
@@ -230,6 +388,45 @@ extern "C" {
     fn action(this: &DialogActionType) -> String;
 }
 
-fn action_from_event(event: &Event) -> String {
-    event_details_into::<DialogActionType>(event).action()
+/// Whether `event` was sent by `dialog` itself. Menus and selects in the
+/// dialog send `opened` and `closed` events which bubble.
+fn is_dialog_event(event: &Event, dialog: &Element) -> bool {
+    let dialog: &EventTarget = dialog.as_ref();
+    event.target().as_ref() == Some(dialog)
+}
+
+fn action_from_event<A: FromStr>(event: &Event) -> DialogAction<A> {
+    event_details_into::<DialogActionType>(event)
+        .action()
+        .parse()
+        .unwrap_or_else(|never| match never {})
+}
+
+/// Emits `onbeforeclose` for `action`, returning whether closing was
+/// prevented. The empty action doesn't close the dialog.
+fn close_prevented<A: FromStr>(
+    onbeforeclose: &Callback<BeforeCloseEvent<A>>,
+    action: &str,
+) -> bool {
+    if action.is_empty() {
+        return false;
+    }
+    let action = action.parse().unwrap_or_else(|never| match never {});
+    let event = BeforeCloseEvent::new(action);
+    let prevented = event.prevented();
+    onbeforeclose.emit(event);
+    prevented.get()
+}
+
+fn property_string(element: &Element, property: &str) -> String {
+    js_sys::Reflect::get(element, &JsValue::from(property))
+        .ok()
+        .and_then(|value| value.as_string())
+        .unwrap_or_default()
+}
+
+/// Value of an attribute taking an action, where an empty string disables the
+/// action
+fn action_attribute<A: Display>(action: Option<&DialogAction<A>>) -> Cow<'static, str> {
+    Cow::from(action.map(|action| action.to_string()).unwrap_or_default())
 }
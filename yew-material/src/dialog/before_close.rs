@@ -0,0 +1,39 @@
+use super::DialogAction;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A request to close a [`MatDialog`][crate::MatDialog], emitted by
+/// `onbeforeclose`
+///
+/// Calling [`prevent_default`][Self::prevent_default] keeps the dialog open.
+/// It only has an effect while the callback runs, so it must be called from
+/// the callback itself or from the `update` of the component the callback
+/// was created with.
+#[derive(Debug)]
+pub struct BeforeCloseEvent<A> {
+    action: DialogAction<A>,
+    prevented: Rc<Cell<bool>>,
+}
+
+impl<A> BeforeCloseEvent<A> {
+    pub(crate) fn new(action: DialogAction<A>) -> Self {
+        Self {
+            action,
+            prevented: Rc::default(),
+        }
+    }
+
+    /// The action the dialog would close with
+    pub fn action(&self) -> &DialogAction<A> {
+        &self.action
+    }
+
+    /// Keeps the dialog open
+    pub fn prevent_default(&self) {
+        self.prevented.set(true);
+    }
+
+    pub(crate) fn prevented(&self) -> Rc<Cell<bool>> {
+        Rc::clone(&self.prevented)
+    }
+}
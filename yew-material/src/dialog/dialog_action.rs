@@ -1,7 +1,58 @@
 use std::borrow::Cow;
+use std::convert::Infallible;
 use std::fmt;
+use std::str::FromStr;
 use yew::prelude::*;
 
+/// The action a [`MatDialog`][crate::MatDialog] closes with
+///
+/// `dialogAction` attributes and the actions reported by `closing` and `closed`
+/// events are strings. The built-in ones are `close`, `scrim` and `escape`,
+/// and any other string is parsed as `A`. Values of `A` displayed as one of
+/// the built-in strings are therefore read back as the built-in variant.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogAction<A> {
+    /// The dialog was closed by setting `open` to `false`, or by calling
+    /// `close()`
+    Close,
+    /// The scrim was clicked
+    Scrim,
+    /// `Escape` was pressed
+    Escape,
+    /// An element with this `dialogAction` was clicked
+    Custom(A),
+    /// An action which isn't a built-in one and couldn't be parsed as `A`
+    Unknown(String),
+}
+
+impl<A: FromStr> FromStr for DialogAction<A> {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "close" => DialogAction::Close,
+            "scrim" => DialogAction::Scrim,
+            "escape" => DialogAction::Escape,
+            _ => match A::from_str(s) {
+                Ok(action) => DialogAction::Custom(action),
+                Err(_) => DialogAction::Unknown(s.to_string()),
+            },
+        })
+    }
+}
+
+impl<A: fmt::Display> fmt::Display for DialogAction<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DialogAction::Close => write!(f, "close"),
+            DialogAction::Scrim => write!(f, "scrim"),
+            DialogAction::Escape => write!(f, "escape"),
+            DialogAction::Custom(action) => write!(f, "{}", action),
+            DialogAction::Unknown(action) => write!(f, "{}", action),
+        }
+    }
+}
+
 /// Dialog action type.
 #[derive(Clone)]
 pub enum ActionType {
@@ -29,10 +80,15 @@ impl fmt::Display for ActionType {
 
 /// Props for [`MatDialogAction`]
 #[derive(Properties, Clone)]
-pub struct ActionProps {
-    pub action_type: ActionType,
+pub struct ActionProps<A: FromStr + fmt::Display + Clone + 'static> {
+    /// The slot of the dialog the child is placed in. Without it, the child
+    /// stays where it is rendered in the content of the dialog.
     #[prop_or_default]
-    pub action: Option<Cow<'static, str>>,
+    pub action_type: Option<ActionType>,
+    /// The action the dialog closes with when the child is clicked, usually
+    /// `DialogAction::Custom(action)` or [`DialogAction::Close`]
+    #[prop_or_default]
+    pub action: Option<DialogAction<A>>,
     pub children: Children,
 }
 
@@ -41,13 +97,15 @@ pub struct ActionProps {
 /// If the child passed is an element (a `VTag`), then it is modified to include
 /// the appropriate attributes. Otherwise, the child is wrapped in a `span`
 /// containing said attributes.
-pub struct MatDialogAction {
-    props: ActionProps,
+///
+/// `A` must be the action type of the dialog.
+pub struct MatDialogAction<A: FromStr + fmt::Display + Clone + 'static = String> {
+    props: ActionProps<A>,
 }
 
-impl Component for MatDialogAction {
+impl<A: FromStr + fmt::Display + Clone + 'static> Component for MatDialogAction<A> {
     type Message = ();
-    type Properties = ActionProps;
+    type Properties = ActionProps<A>;
 
     fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
         Self { props }
@@ -63,17 +121,25 @@ impl Component for MatDialogAction {
     }
 
     fn view(&self) -> Html {
+        let action = self.props.action.as_ref().map(|action| action.to_string());
+        let slot = self
+            .props
+            .action_type
+            .as_ref()
+            .map(|action_type| action_type.to_cow_string());
         let children = self.props.children.iter().map(|child| {
             match child {
                 Html::VTag(mut vtag) => {
-                    vtag.add_attribute("slot", self.props.action_type.to_string());
-                    if let Some(action) = self.props.action.as_ref() {
-                        vtag.add_attribute("dialogAction", action.to_owned());
+                    if let Some(slot) = slot.clone() {
+                        vtag.add_attribute("slot", slot);
+                    }
+                    if let Some(action) = action.clone() {
+                        vtag.add_attribute("dialogAction", action);
                     }
                     Html::VTag(vtag)
                 }
                 _ => html! {
-                    <span slot=self.props.action_type.to_string() dialogAction=self.props.action.clone()>
+                    <span slot=slot.clone() dialogAction=action.clone().map(Cow::from)>
                         { child }
                     </span>
                 }
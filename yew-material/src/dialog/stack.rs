@@ -0,0 +1,176 @@
+//! Stack of the dialogs shown by `show_stacked` and `show_queued`
+//!
+//! Every `mwc-dialog` reacts to `Escape`, so only the top dialog keeps its
+//! `escapeKeyAction`. Dialogs higher in the stack are also given a higher
+//! `z-index` so their scrim covers the dialogs below.
+//!
+//! `mwc-dialog` sets the `z-index` of its `.mdc-dialog` element, in its shadow
+//! root, to 7, so the `z-index` is set inline on that element. It is set on
+//! the dialog too, which only matters if the dialog is positioned.
+
+use super::{is_dialog_event, Dialog};
+use gloo::events::EventListener;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlElement;
+
+/// `z-index` of `.mdc-dialog` in the shadow root of `mwc-dialog`
+const BASE_Z_INDEX: usize = 7;
+
+thread_local! {
+    static STACK: RefCell<DialogStack> = RefCell::default();
+}
+
+#[derive(Default)]
+struct DialogStack {
+    open: Vec<Entry>,
+    queue: VecDeque<HtmlElement>,
+}
+
+struct Entry {
+    dialog: HtmlElement,
+    z_index: usize,
+    /// The element focused before the dialog was shown
+    restore_focus: Option<HtmlElement>,
+    /// `escapeKeyAction` of the dialog while it is covered by another one
+    escape_key_action: Option<JsValue>,
+    _closed_listener: EventListener,
+}
+
+/// Shows `dialog` on top of the open dialogs
+pub(crate) fn push(dialog: HtmlElement) {
+    let shown = STACK.with(|stack| stack.borrow_mut().open(dialog.clone()));
+    if shown {
+        dialog.unchecked_ref::<Dialog>().show();
+    }
+}
+
+/// Shows `dialog` once no dialog of the stack is open
+pub(crate) fn enqueue(dialog: HtmlElement) {
+    let is_empty = STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let is_empty = stack.open.is_empty() && stack.queue.is_empty();
+        if !is_empty && !stack.queue.contains(&dialog) {
+            stack.queue.push_back(dialog.clone());
+        }
+        is_empty
+    });
+    if is_empty {
+        push(dialog);
+    }
+}
+
+/// Removes `dialog` from the stack and shows the next queued dialog if the
+/// stack is empty
+fn closed(dialog: HtmlElement) {
+    let next = STACK.with(|stack| stack.borrow_mut().close(&dialog));
+    if let Some(next) = next {
+        push(next);
+    }
+}
+
+impl DialogStack {
+    /// Adds `dialog` on top of the stack, returning whether it wasn't in the
+    /// stack already
+    fn open(&mut self, dialog: HtmlElement) -> bool {
+        if self.open.iter().any(|entry| entry.dialog == dialog) {
+            return false;
+        }
+
+        if let Some(top) = self.open.last_mut() {
+            let action = js_sys::Reflect::get(&top.dialog, &JsValue::from("escapeKeyAction")).ok();
+            let _ = js_sys::Reflect::set(
+                &top.dialog,
+                &JsValue::from("escapeKeyAction"),
+                &JsValue::from(""),
+            );
+            top.escape_key_action = action;
+        }
+
+        // dialogs below the top may have closed, so the top one has the
+        // highest `z-index`
+        let z_index = self.open.last().map_or(BASE_Z_INDEX, |top| top.z_index + 1);
+        set_z_index(&dialog, Some(&z_index.to_string()));
+
+        let restore_focus = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+        let element = dialog.clone();
+        // the listener is dropped when the dialog is removed from the stack,
+        // which mustn't happen while it is running
+        let closed_listener = EventListener::new(&dialog, "closed", move |event| {
+            if !is_dialog_event(event, &element) {
+                return;
+            }
+            let element = element.clone();
+            spawn_local(async move { closed(element) });
+        });
+
+        self.open.push(Entry {
+            dialog,
+            z_index,
+            restore_focus,
+            escape_key_action: None,
+            _closed_listener: closed_listener,
+        });
+        true
+    }
+
+    /// Removes `dialog` from the stack, returning the queued dialog to show
+    /// next
+    fn close(&mut self, dialog: &HtmlElement) -> Option<HtmlElement> {
+        let index = self.open.iter().position(|entry| entry.dialog == *dialog)?;
+        let entry = self.open.remove(index);
+        set_z_index(&entry.dialog, None);
+
+        if let Some(above) = self.open.get_mut(index) {
+            // the element it would have focused was in the closed dialog
+            above.restore_focus = entry.restore_focus;
+        } else {
+            if let Some(top) = self.open.last_mut() {
+                if let Some(action) = top.escape_key_action.take() {
+                    let _ = js_sys::Reflect::set(
+                        &top.dialog,
+                        &JsValue::from("escapeKeyAction"),
+                        &action,
+                    );
+                }
+            }
+            if let Some(element) = entry.restore_focus {
+                if element.is_connected() {
+                    let _ = element.focus();
+                }
+            }
+        }
+
+        if !self.open.is_empty() {
+            return None;
+        }
+        // dialogs removed from the page while queued are skipped
+        while let Some(next) = self.queue.pop_front() {
+            if next.is_connected() {
+                return Some(next);
+            }
+        }
+        None
+    }
+}
+
+/// Sets the `z-index` of `dialog` and of its `.mdc-dialog` element, or removes
+/// it if `z_index` is `None`
+fn set_z_index(dialog: &HtmlElement, z_index: Option<&str>) {
+    let surface = dialog
+        .shadow_root()
+        .and_then(|root| root.query_selector(".mdc-dialog").ok().flatten())
+        .and_then(|surface| surface.dyn_into::<HtmlElement>().ok());
+    for element in std::iter::once(dialog).chain(surface.as_ref()) {
+        let _ = match z_index {
+            Some(z_index) => element.style().set_property("z-index", z_index),
+            None => element.style().remove_property("z-index").map(|_| ()),
+        };
+    }
+}
@@ -0,0 +1,268 @@
+//! Dialogs which are shown by calling a function and awaiting its result
+//!
+//! The dialogs are rendered in an element appended to the body of the document
+//! when the first one is shown. The returned future resolves with the outcome
+//! once the dialog has closed, and the dialog is then removed.
+//!
+//! ```no_run
+//! # use yew_material::dialog_service::confirm;
+//! # async fn delete() {
+//! if confirm("Delete file?", "This can't be undone.").await {
+//!     // delete the file
+//! }
+//! # }
+//! ```
+
+use crate::dialog::{ActionType, DialogAction, MatDialogAction};
+use crate::{MatButton, MatDialog, MatTextField};
+use futures_channel::oneshot;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use yew::prelude::*;
+
+thread_local! {
+    /// Link of the outlet the dialogs are rendered in, once it's mounted
+    static OUTLET: RefCell<Option<ComponentLink<ServiceDialogOutlet>>> = const { RefCell::new(None) };
+}
+
+/// Actions of the buttons of a service dialog
+#[derive(Clone, Copy, PartialEq)]
+enum ServiceAction {
+    Ok,
+    Cancel,
+}
+
+impl FromStr for ServiceAction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ok" => Ok(ServiceAction::Ok),
+            "cancel" => Ok(ServiceAction::Cancel),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for ServiceAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceAction::Ok => write!(f, "ok"),
+            ServiceAction::Cancel => write!(f, "cancel"),
+        }
+    }
+}
+
+/// Shows a dialog asking the user to confirm something.
+///
+/// Resolves to `true` if the user pressed "OK", and to `false` if the dialog
+/// was closed in any other way.
+pub async fn confirm(
+    title: impl Into<Cow<'static, str>>,
+    body: impl Into<Cow<'static, str>>,
+) -> bool {
+    let props = ServiceDialogProps {
+        title: title.into(),
+        body: body.into(),
+        prompt: None,
+        ondone: Callback::noop(),
+    };
+    show(props).await.is_some()
+}
+
+/// Shows a dialog asking the user to enter some text in a text field labelled
+/// `label`, initially containing `value`.
+///
+/// Resolves to the entered text if the user pressed "OK", and to `None` if the
+/// dialog was closed in any other way.
+pub async fn prompt(
+    title: impl Into<Cow<'static, str>>,
+    label: impl Into<Cow<'static, str>>,
+    value: impl Into<String>,
+) -> Option<String> {
+    let props = ServiceDialogProps {
+        title: title.into(),
+        body: Cow::from(""),
+        prompt: Some((label.into(), value.into())),
+        ondone: Callback::noop(),
+    };
+    show(props).await
+}
+
+/// Shows a dialog with `props` and waits for it to close.
+///
+/// Resolves to what the dialog emitted through `ondone`.
+async fn show(props: ServiceDialogProps) -> Option<String> {
+    let (sender, receiver) = oneshot::channel();
+    OUTLET.with(|outlet| {
+        outlet
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                let host = document.create_element("div").unwrap();
+                document.body().unwrap().append_child(&host).unwrap();
+                yew::App::<ServiceDialogOutlet>::new().mount(host)
+            })
+            .send_message(OutletMsg::Show(props, sender))
+    });
+    // the sender is dropped without sending if the outlet is destroyed
+    receiver.await.unwrap_or(None)
+}
+
+/// Renders the dialogs shown by [`show`]
+///
+/// A dialog is removed from the outlet, destroying its component, once it has
+/// closed.
+struct ServiceDialogOutlet {
+    link: ComponentLink<Self>,
+    next_id: usize,
+    dialogs: Vec<(usize, ServiceDialogProps)>,
+    senders: HashMap<usize, oneshot::Sender<Option<String>>>,
+}
+
+enum OutletMsg {
+    Show(ServiceDialogProps, oneshot::Sender<Option<String>>),
+    Done(usize, Option<String>),
+}
+
+impl Component for ServiceDialogOutlet {
+    type Message = OutletMsg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            next_id: 0,
+            dialogs: Vec::new(),
+            senders: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            OutletMsg::Show(mut props, sender) => {
+                let id = self.next_id;
+                self.next_id += 1;
+                props.ondone = self
+                    .link
+                    .callback(move |result| OutletMsg::Done(id, result));
+                self.dialogs.push((id, props));
+                self.senders.insert(id, sender);
+            }
+            OutletMsg::Done(id, result) => {
+                self.dialogs.retain(|(it, _)| *it != id);
+                if let Some(sender) = self.senders.remove(&id) {
+                    let _ = sender.send(result);
+                }
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> bool {
+        false
+    }
+
+    fn view(&self) -> Html {
+        self.dialogs
+            .iter()
+            .map(|(id, props)| {
+                html! {
+                    <ServiceDialog
+                        key=*id
+                        title=props.title.clone()
+                        body=props.body.clone()
+                        prompt=props.prompt.clone()
+                        ondone=props.ondone.clone()
+                    />
+                }
+            })
+            .collect()
+    }
+}
+
+/// The dialog shown by [`confirm`] and [`prompt`]
+struct ServiceDialog {
+    props: ServiceDialogProps,
+    link: ComponentLink<Self>,
+    value: String,
+}
+
+#[derive(Properties, Clone)]
+struct ServiceDialogProps {
+    title: Cow<'static, str>,
+    body: Cow<'static, str>,
+    /// Label and initial value of the text field of a prompt
+    prompt: Option<(Cow<'static, str>, String)>,
+    /// Emits the text of the field if the user pressed "OK", which is empty
+    /// for a confirmation, and `None` otherwise
+    ondone: Callback<Option<String>>,
+}
+
+enum Msg {
+    Input(String),
+    Closed(DialogAction<ServiceAction>),
+}
+
+impl Component for ServiceDialog {
+    type Message = Msg;
+    type Properties = ServiceDialogProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let value = props
+            .prompt
+            .as_ref()
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default();
+        Self { props, link, value }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(value) => self.value = value,
+            Msg::Closed(action) => {
+                let confirmed = action == DialogAction::Custom(ServiceAction::Ok);
+                self.props
+                    .ondone
+                    .emit(confirmed.then(|| self.value.clone()));
+            }
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let content = match self.props.prompt.as_ref() {
+            Some((label, value)) => html! {
+                <MatTextField
+                    label=label.clone()
+                    value=Cow::from(value.clone())
+                    oninput=self.link.callback(|data: InputData| Msg::Input(data.value))
+                />
+            },
+            None => html! { <p>{ self.props.body.clone() }</p> },
+        };
+        html! {
+            <MatDialog<ServiceAction>
+                open=true
+                heading=self.props.title.clone()
+                onclosed=self.link.callback(Msg::Closed)
+            >
+                { content }
+                <MatDialogAction<ServiceAction> action_type=ActionType::Primary action=DialogAction::Custom(ServiceAction::Ok)>
+                    <MatButton label="OK" />
+                </MatDialogAction<ServiceAction>>
+                <MatDialogAction<ServiceAction> action_type=ActionType::Secondary action=DialogAction::Custom(ServiceAction::Cancel)>
+                    <MatButton label="Cancel" />
+                </MatDialogAction<ServiceAction>>
+            </MatDialog<ServiceAction>>
+        }
+    }
+}
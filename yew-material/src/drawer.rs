@@ -2,15 +2,16 @@ mod drawer_app_content;
 mod drawer_header;
 mod drawer_subtitle;
 mod drawer_title;
+mod drawer_type;
 
 pub use drawer_app_content::*;
 pub use drawer_header::*;
 pub use drawer_subtitle::*;
 pub use drawer_title::*;
+pub use drawer_type::*;
 
 use crate::{bool_to_option, WeakComponentLink};
 use gloo::events::EventListener;
-use std::borrow::Cow;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
 use yew::prelude::*;
@@ -39,11 +40,24 @@ loader_hack!(Drawer);
 /// The `mwc-drawer` component
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/master/packages/drawer)
+///
+/// ## Open state
+///
+/// `open` is controlled: whenever the component is rendered while the drawer
+/// isn't in the state `open` asks for, the drawer is opened or closed. A
+/// dismissible or modal drawer can also be opened and closed by the user or
+/// through `drawer_link`, which is reported by `onopened` and `onclosed`.
+/// `open` must be updated from them, or the drawer goes back to the state
+/// `open` asks for the next time the component is rendered.
 pub struct MatDrawer {
     props: DrawerProps,
+    link: ComponentLink<Self>,
     node_ref: NodeRef,
     opened_listener: Option<EventListener>,
     closed_listener: Option<EventListener>,
+    /// Whether the drawer is open, as reported by `MDCDrawer:opened` and
+    /// `MDCDrawer:closed`, or as it was last set to
+    open: bool,
 }
 
 /// Props for [`MatDrawer`]
@@ -61,7 +75,7 @@ pub struct DrawerProps {
     #[prop_or_default]
     pub has_header: bool,
     #[prop_or_default]
-    pub drawer_type: Cow<'static, str>,
+    pub drawer_type: DrawerType,
     /// Binds to `opened` event on `mwc-drawer`
     ///
     /// See events docs to learn more.
@@ -72,27 +86,52 @@ pub struct DrawerProps {
     /// See events docs to learn more.
     #[prop_or_default]
     pub onclosed: Callback<()>,
+    /// [`WeakComponentLink`] for `MatDrawer` which provides the following
+    /// methods:
+    /// - ```open(&self)```
+    /// - ```close(&self)```
+    /// - ```is_open(&self) -> bool```
+    /// - ```flip_open_state(&self)```
+    ///
+    /// See [`WeakComponentLink`] documentation for more information
     #[prop_or_default]
     pub drawer_link: WeakComponentLink<MatDrawer>,
     pub children: Children,
 }
 
+pub enum Msg {
+    Opened,
+    Closed,
+}
+
 impl Component for MatDrawer {
-    type Message = ();
+    type Message = Msg;
     type Properties = DrawerProps;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        props.drawer_link.borrow_mut().replace(link);
+        props.drawer_link.borrow_mut().replace(link.clone());
         Drawer::ensure_loaded();
         Self {
             props,
+            link,
             node_ref: NodeRef::default(),
             opened_listener: None,
             closed_listener: None,
+            open: false,
         }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Opened => {
+                self.open = true;
+                self.props.onopened.emit(());
+            }
+            Msg::Closed => {
+                self.open = false;
+                self.props.onclosed.emit(());
+            }
+        }
         false
     }
 
@@ -111,41 +150,63 @@ impl Component for MatDrawer {
 
     fn rendered(&mut self, _first_render: bool) {
         let element = self.node_ref.cast::<Drawer>().unwrap();
-        element.set_type(&JsValue::from(self.props.drawer_type.as_ref()));
-        element.set_open(self.props.open);
+        element.set_type(&JsValue::from(self.props.drawer_type.as_str()));
+        if self.props.open != self.open {
+            element.set_open(self.props.open);
+            self.open = self.props.open;
+        }
 
         if self.opened_listener.is_none() {
-            let onopen_callback = self.props.onopened.clone();
+            let link = self.link.clone();
             self.opened_listener = Some(EventListener::new(
                 &element,
                 "MDCDrawer:opened",
-                move |_| {
-                    onopen_callback.emit(());
-                },
+                move |_| link.send_message(Msg::Opened),
             ));
         }
 
         if self.closed_listener.is_none() {
-            let onclose_callback = self.props.onclosed.clone();
+            let link = self.link.clone();
             self.closed_listener = Some(EventListener::new(
                 &element,
                 "MDCDrawer:closed",
-                move |_| {
-                    onclose_callback.emit(());
-                },
+                move |_| link.send_message(Msg::Closed),
             ));
         }
     }
 }
 
 impl WeakComponentLink<MatDrawer> {
+    /// Opens the drawer. `onopened` is emitted once it has opened.
+    ///
+    /// The drawer is closed again when rendered if `open` prop isn't updated.
+    pub fn open(&self) {
+        self.drawer().set_open(true);
+    }
+
+    /// Closes the drawer. `onclosed` is emitted once it has closed.
+    ///
+    /// The drawer is opened again when rendered if `open` prop isn't updated.
+    pub fn close(&self) {
+        self.drawer().set_open(false);
+    }
+
+    /// Whether the drawer is open or opening
+    pub fn is_open(&self) -> bool {
+        self.drawer().open()
+    }
+
     /// A convenience method to for `drawer.open = !drawer.open`
     pub fn flip_open_state(&self) {
-        let node_ref = (*self.borrow().as_ref().unwrap().get_component().unwrap())
-            .node_ref
-            .clone();
-        let element = node_ref.cast::<Drawer>().unwrap();
+        let element = self.drawer();
         let open = element.open();
         element.set_open(!open);
     }
+
+    fn drawer(&self) -> Drawer {
+        (*self.borrow().as_ref().unwrap().get_component().unwrap())
+            .node_ref
+            .cast::<Drawer>()
+            .unwrap()
+    }
 }
@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// The type of a [`MatDrawer`][crate::MatDrawer]
+///
+/// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/drawer#propertiesattributes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DrawerType {
+    /// A drawer which is always shown. Binds to an empty `type`.
+    #[default]
+    Standard,
+    /// Binds to `dismissible`
+    Dismissible,
+    /// Binds to `modal`
+    Modal,
+}
+
+impl DrawerType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DrawerType::Standard => "",
+            DrawerType::Dismissible => "dismissible",
+            DrawerType::Modal => "modal",
+        }
+    }
+}
+
+impl fmt::Display for DrawerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,215 @@
+use crate::dialog::{ActionType, BeforeCloseEvent, DialogAction, MatDialogAction};
+use crate::top_app_bar::{MatTopAppBarActionItems, MatTopAppBarNavigationIcon, MatTopAppBarTitle};
+use crate::{MatButton, MatDialog, MatIconButton, MatTopAppBar, WeakComponentLink};
+use gloo::events::EventListener;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+use web_sys::MediaQueryList;
+use yew::prelude::*;
+
+/// Makes the surface of the dialog cover the viewport. Custom properties are
+/// inherited by the shadow root of `mwc-dialog`.
+const FULL_SCREEN_STYLE: &str = "--mdc-dialog-min-width: 100vw; \
+    --mdc-dialog-max-width: 100vw; --mdc-dialog-max-height: 100%; \
+    --mdc-shape-medium: 0px;";
+
+/// The content is slotted into `.mdc-dialog__content`, which has a padding of
+/// 20px 24px
+const CONTENT_STYLE: &str = "min-height: calc(100vh - 40px);";
+
+/// Buttons use the primary color for their text, which is the color of the bar
+const BAR_BUTTON_STYLE: &str = "--mdc-theme-primary: var(--mdc-theme-on-primary, #fff);";
+
+/// A [`MatDialog`] covering the whole viewport
+///
+/// The heading, a close button and the primary action are shown in a
+/// [`MatTopAppBar`] at the top of the dialog.
+///
+/// When `max_width` is set, the dialog is only full-screen while the viewport
+/// is at most that wide, and is otherwise shown as a regular [`MatDialog`] with
+/// the primary action and a dismiss button as its actions.
+///
+/// The close button and the dismiss button close the dialog with
+/// [`DialogAction::Close`], and the primary action closes it with
+/// `DialogAction::Custom(action)`.
+pub struct MatFullScreenDialog<A: FromStr + Display + Clone + 'static = String> {
+    props: FullScreenDialogProps<A>,
+    link: ComponentLink<Self>,
+    media_query: Option<MediaQueryList>,
+    media_query_listener: Option<EventListener>,
+    full_screen: bool,
+}
+
+/// Props for [`MatFullScreenDialog`]
+#[derive(Properties, Clone)]
+pub struct FullScreenDialogProps<A: FromStr + Display + Clone + 'static> {
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub open: bool,
+    pub heading: Cow<'static, str>,
+    /// Label of the button of the primary action
+    pub action_label: Cow<'static, str>,
+    /// The primary action
+    pub action: A,
+    /// Label of the close button, also used as the `aria-label` of the close
+    /// icon of the bar
+    #[prop_or(Cow::from("Close"))]
+    pub dismiss_label: Cow<'static, str>,
+    /// Largest width of the viewport, in pixels, for which the dialog is
+    /// full-screen. The dialog is always full-screen when it isn't set.
+    #[prop_or_default]
+    pub max_width: Option<u32>,
+    /// Binds to `onopened` of [`MatDialog`]
+    #[prop_or_default]
+    pub onopened: Callback<()>,
+    /// Binds to `onclosing` of [`MatDialog`]
+    #[prop_or_default]
+    pub onclosing: Callback<DialogAction<A>>,
+    /// Binds to `onbeforeclose` of [`MatDialog`]
+    #[prop_or_default]
+    pub onbeforeclose: Callback<BeforeCloseEvent<A>>,
+    /// Binds to `onclosed` of [`MatDialog`]
+    #[prop_or_default]
+    pub onclosed: Callback<DialogAction<A>>,
+    /// Link of the underlying [`MatDialog`]
+    #[prop_or_default]
+    pub dialog_link: WeakComponentLink<MatDialog<A>>,
+    pub children: Children,
+}
+
+pub enum Msg {
+    MediaChanged,
+}
+
+impl<A: FromStr + Display + Clone + 'static> Component for MatFullScreenDialog<A> {
+    type Message = Msg;
+    type Properties = FullScreenDialogProps<A>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut dialog = Self {
+            props,
+            link,
+            media_query: None,
+            media_query_listener: None,
+            full_screen: true,
+        };
+        dialog.watch_viewport();
+        dialog
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::MediaChanged => {
+                let full_screen = self.matches_viewport();
+                let changed = full_screen != self.full_screen;
+                self.full_screen = full_screen;
+                changed
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        let max_width_changed = props.max_width != self.props.max_width;
+        self.props = props;
+        if max_width_changed {
+            self.watch_viewport();
+        }
+        true
+    }
+
+    fn view(&self) -> Html {
+        // the same `MatDialog` is rendered in both modes so that switching
+        // keeps it open
+        let (style, heading, content) = if self.full_screen {
+            (FULL_SCREEN_STYLE, None, self.view_full_screen())
+        } else {
+            ("", Some(self.props.heading.clone()), self.view_regular())
+        };
+        html! {
+            <div style=style>
+                <MatDialog<A>
+                    classes=self.props.classes.clone()
+                    open=self.props.open
+                    hide_action=self.full_screen
+                    heading=heading
+                    onopened=self.props.onopened.clone()
+                    onclosing=self.props.onclosing.clone()
+                    onbeforeclose=self.props.onbeforeclose.clone()
+                    onclosed=self.props.onclosed.clone()
+                    dialog_link=self.props.dialog_link.clone()
+                >
+                    { content }
+                </MatDialog<A>>
+            </div>
+        }
+    }
+}
+
+impl<A: FromStr + Display + Clone + 'static> MatFullScreenDialog<A> {
+    /// Listens to the viewport becoming narrower or wider than `max_width`
+    fn watch_viewport(&mut self) {
+        self.media_query_listener = None;
+        self.media_query = self.props.max_width.and_then(|max_width| {
+            web_sys::window()?
+                .match_media(&format!("(max-width: {}px)", max_width))
+                .ok()
+                .flatten()
+        });
+        self.full_screen = self.matches_viewport();
+        if let Some(query) = self.media_query.as_ref() {
+            let link = self.link.clone();
+            self.media_query_listener = Some(EventListener::new(query, "change", move |_| {
+                link.send_message(Msg::MediaChanged)
+            }));
+        }
+    }
+
+    /// Whether the dialog should be full-screen at the current viewport width
+    fn matches_viewport(&self) -> bool {
+        match self.media_query.as_ref() {
+            Some(query) => query.matches(),
+            None => true,
+        }
+    }
+
+    /// The bar and the children
+    fn view_full_screen(&self) -> Html {
+        html! {
+            <div style=CONTENT_STYLE>
+                <MatTopAppBar>
+                    <MatTopAppBarNavigationIcon>
+                        <MatDialogAction<A> action=DialogAction::Close>
+                            <MatIconButton icon="close" label=self.props.dismiss_label.clone() />
+                        </MatDialogAction<A>>
+                    </MatTopAppBarNavigationIcon>
+                    <MatTopAppBarTitle>{ self.props.heading.clone() }</MatTopAppBarTitle>
+                    <MatTopAppBarActionItems>
+                        <MatDialogAction<A> action=DialogAction::Custom(self.props.action.clone())>
+                            <span style=BAR_BUTTON_STYLE>
+                                <MatButton label=self.props.action_label.to_string() />
+                            </span>
+                        </MatDialogAction<A>>
+                    </MatTopAppBarActionItems>
+                    { self.props.children.clone() }
+                </MatTopAppBar>
+            </div>
+        }
+    }
+
+    /// The children and the actions
+    fn view_regular(&self) -> Html {
+        html! {
+            <>
+                { self.props.children.clone() }
+                <MatDialogAction<A> action_type=ActionType::Primary action=DialogAction::Custom(self.props.action.clone())>
+                    <MatButton label=self.props.action_label.to_string() />
+                </MatDialogAction<A>>
+                <MatDialogAction<A> action_type=ActionType::Secondary action=DialogAction::Close>
+                    <MatButton label=self.props.dismiss_label.to_string() />
+                </MatDialogAction<A>>
+            </>
+        }
+    }
+}
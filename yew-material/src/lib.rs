@@ -1,4 +1,6 @@
 #![doc(html_root_url = "/docs")]
+// `html!` checks the props of components with statements clippy flags
+#![allow(clippy::unnecessary_operation)]
 
 //! A Material components library for [Yew](https://yew.rs). It wrpas around [Material Web Components](https://github.com/material-components/material-components-web-components) exposing Yew components.
 //!
@@ -186,12 +188,21 @@ pub mod dialog;
 #[doc(hidden)]
 pub use dialog::MatDialog;
 
+#[cfg(feature = "dialog-service")]
+pub mod dialog_service;
+
+#[cfg(feature = "full-screen-dialog")]
+pub mod full_screen_dialog;
+#[cfg(feature = "full-screen-dialog")]
+#[doc(hidden)]
+pub use full_screen_dialog::MatFullScreenDialog;
+
 #[cfg(feature = "list")]
 pub mod list;
 #[cfg(feature = "list")]
 #[doc(no_inline)]
 #[doc(hidden)]
-pub use list::{MatCheckListItem, MatList, MatListItem, MatRadioListItem};
+pub use list::{MatCheckListItem, MatList, MatListItem, MatRadioListItem, MatVirtualList};
 
 #[cfg(feature = "icon-button-toggle")]
 pub mod icon_button_toggle;
@@ -218,6 +229,12 @@ pub mod snackbar;
 #[doc(hidden)]
 pub use snackbar::MatSnackbar;
 
+#[cfg(feature = "snackbar-queue")]
+pub mod snackbar_queue;
+#[cfg(feature = "snackbar-queue")]
+#[doc(hidden)]
+pub use snackbar_queue::MatSnackbarHost;
+
 #[cfg(any(feature = "textfield", feature = "textarea"))]
 pub mod text_inputs;
 #[cfg(feature = "textarea")]
@@ -239,7 +256,25 @@ pub use select::MatSelect;
 pub mod menu;
 #[cfg(feature = "menu")]
 #[doc(hidden)]
-pub use menu::MatMenu;
+pub use menu::{MatContextMenu, MatMenu, MatSubMenu};
+
+#[cfg(feature = "menu-surface")]
+pub mod menu_surface;
+#[cfg(feature = "menu-surface")]
+#[doc(hidden)]
+pub use menu_surface::MatMenuSurface;
+
+#[cfg(feature = "multi-select")]
+pub mod multi_select;
+#[cfg(feature = "multi-select")]
+#[doc(hidden)]
+pub use multi_select::MatMultiSelect;
+
+#[cfg(feature = "menu-button")]
+pub mod menu_button;
+#[cfg(feature = "menu-button")]
+#[doc(hidden)]
+pub use menu_button::MatMenuButton;
 
 use std::borrow::Cow;
 use std::fmt::Display;
@@ -22,12 +22,40 @@ pub use request_selected::{RequestSelectedDetail, RequestSelectedSource};
 mod graphic_type;
 pub use graphic_type::GraphicType;
 
+mod virtual_list;
+pub use virtual_list::{ItemHeight, ItemRenderer, MatVirtualList, VirtualListProps};
+
+mod list_items;
+pub use list_items::ListItems;
+
+mod list_option;
+pub use list_option::ListOption;
+
+mod reorder;
+
+mod end_observer;
+
+mod loading_indicator;
+pub use loading_indicator::LoadingIndicator;
+
+use crate::utils::update_complete;
+#[cfg(feature = "circular-progress")]
+use crate::MatCircularProgress;
+#[cfg(feature = "linear-progress")]
+use crate::MatLinearProgress;
 use crate::{bool_to_option, event_into_details, WeakComponentLink};
+use end_observer::EndObserver;
 use gloo::events::EventListener;
+use reorder::Reorderable;
 use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
-use web_sys::Node;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{Element, HtmlElement, Node};
 use yew::prelude::*;
+use yew::virtual_dom::{Key, VList};
 
 #[wasm_bindgen(module = "/build/mwc-list.js")]
 extern "C" {
@@ -41,6 +69,18 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     fn index(this: &List) -> JsValue;
 
+    #[wasm_bindgen(method, getter)]
+    fn items(this: &List) -> js_sys::Array;
+
+    #[wasm_bindgen(method, getter)]
+    fn selected(this: &List) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn select(this: &List, index: &JsValue);
+
+    #[wasm_bindgen(method)]
+    fn layout(this: &List, update_items: bool);
+
     #[wasm_bindgen(method)]
     fn toggle(this: &List, index: usize, force: bool);
 
@@ -56,11 +96,27 @@ loader_hack!(List);
 /// The `mwc-list` component
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/master/packages/list)
-pub struct MatList {
-    props: ListProps,
+///
+/// ## Items
+///
+/// Items can either be passed as children or be rendered from data passed
+/// with the `items` prop. In the latter case, `T` is the type of the data and
+/// the items are keyed so that selection stays with the same data when items
+/// are reordered, inserted or removed.
+pub struct MatList<T: Clone + 'static = ()> {
+    props: ListProps<T>,
+    link: ComponentLink<Self>,
     node_ref: NodeRef,
+    selected_keys: HashSet<Key>,
     action_listener: Option<EventListener>,
     selected_listener: Option<EventListener>,
+    /// Set while the element is selecting the controlled selection, whose
+    /// `selected` events aren't the user's
+    syncing: Rc<Cell<bool>>,
+    reorderable: Option<Reorderable>,
+    sentinel_ref: NodeRef,
+    end_observer: Option<(EndObserver, u32)>,
+    loading_finished: bool,
 }
 
 /// Props for [`MatList`]
@@ -70,7 +126,7 @@ pub struct MatList {
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/master/packages/list#mwc-list-1)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/master/packages/list#mwc-list-2)
 #[derive(Properties, Clone)]
-pub struct ListProps {
+pub struct ListProps<T: Clone + 'static> {
     #[prop_or_default]
     pub classes: Classes,
     #[prop_or_default]
@@ -87,43 +143,159 @@ pub struct ListProps {
     pub inner_role: Option<Cow<'static, str>>,
     #[prop_or_default]
     pub noninteractive: bool,
+    /// Controls the selection of the list.
+    ///
+    /// When set, the `index` of the element is reconciled with it whenever the
+    /// list is rendered. A selection the user makes is reported by
+    /// `onselected`, and is reverted the next time the list is rendered unless
+    /// this prop has been updated to it.
+    ///
+    /// It is ignored when `items` is used, as indices don't follow the items
+    /// when they are reordered. Use `selected_keys` instead.
+    #[prop_or_default]
+    pub selected: Option<ListIndex>,
+    /// Controls the selection of the list by the keys of the selected items,
+    /// when `items` is used. It is reconciled like `selected`.
+    #[prop_or_default]
+    pub selected_keys: Option<Vec<Key>>,
+    /// Allows the user to reorder the items by dragging them, or with the
+    /// keyboard: `Space` lifts the focused item, the arrow keys move it,
+    /// `Space` or `Enter` drops it and `Escape` cancels. Check and radio items,
+    /// which `Space` toggles, are lifted with `Alt` + `ArrowUp`/`ArrowDown`.
+    ///
+    /// The list doesn't move the items itself. Use `onreorder` to update the
+    /// order of the items.
+    #[prop_or_default]
+    pub reorderable: bool,
+    /// Emits `(from, to)` when an item is moved from index `from` to index
+    /// `to` by the user
+    #[prop_or_default]
+    pub onreorder: Callback<(usize, usize)>,
+    /// Emits `onendreached` when the end of the list is scrolled into view
+    #[prop_or_default]
+    pub infinite_scroll: bool,
+    /// Distance in pixels from the end of the list at which `onendreached`
+    /// is emitted
+    #[prop_or(200)]
+    pub end_threshold: u32,
+    /// Emitted when the end of the list comes within `end_threshold` pixels
+    /// of the visible area, if `infinite_scroll` is set.
+    ///
+    /// Set `loading` while more items are being fetched: the callback isn't
+    /// emitted during that time, and it is emitted again once `loading` is
+    /// unset if the end of the list is still in view.
+    #[prop_or_default]
+    pub onendreached: Callback<()>,
+    /// Whether more items are being loaded. A loading row is shown after the
+    /// items meanwhile if `loading_indicator` is set.
+    #[prop_or_default]
+    pub loading: bool,
+    /// Progress indicator of the loading row
+    #[prop_or_default]
+    pub loading_indicator: Option<LoadingIndicator>,
     /// Binds to `action` event on `mwc-list`
     #[prop_or_default]
     pub onaction: Callback<ListIndex>,
     /// Binds to `selected` event `mwc-list`
     #[prop_or_default]
     pub onselected: Callback<SelectedDetail>,
+    /// Data to render the items of the list from.
+    ///
+    /// When this is set, `children` are ignored.
+    #[prop_or_default]
+    pub items: Option<ListItems<T>>,
+    /// Emits the data of the selected items whenever the selection changes,
+    /// when `items` is used.
+    ///
+    /// Unless `multi` is set, there is at most one selected item.
+    #[prop_or_default]
+    pub onchange: Callback<Vec<T>>,
     /// [`WeakComponentLink`] for `MatList` which provides the following methods
     /// - ```toggle(&self, index: usize, force: bool)```
     /// - ```get_focused_item_index(&self) -> usize```
     /// - ```focus_item_at_index(&self, index: usize)```
+    /// - ```select(&self, index: &ListIndex)```
+    /// - ```layout(&self, update_items: bool)```
+    /// - ```items(&self) -> Vec<HtmlElement>```
+    /// - ```selected(&self) -> Vec<HtmlElement>```
+    /// - ```index(&self) -> ListIndex```
     ///
     /// See [`WeakComponentLink`] documentation for more information
     #[prop_or_default]
-    pub list_link: WeakComponentLink<MatList>,
+    pub list_link: WeakComponentLink<MatList<T>>,
+    #[prop_or_default]
     pub children: Children,
 }
 
-impl Component for MatList {
-    type Message = ();
-    type Properties = ListProps;
+pub enum Msg {
+    Selected(SelectedDetail),
+    Reorder((usize, usize)),
+    EndReached,
+}
+
+impl<T: Clone + 'static> Component for MatList<T> {
+    type Message = Msg;
+    type Properties = ListProps<T>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        props.list_link.borrow_mut().replace(link);
+        props.list_link.borrow_mut().replace(link.clone());
         List::ensure_loaded();
         Self {
             props,
+            link,
             node_ref: NodeRef::default(),
+            selected_keys: HashSet::new(),
             action_listener: None,
             selected_listener: None,
+            syncing: Rc::default(),
+            reorderable: None,
+            sentinel_ref: NodeRef::default(),
+            end_observer: None,
+            loading_finished: false,
         }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Selected(detail) => {
+                if let Some(items) = self.props.items.as_ref() {
+                    let selected_keys = match &detail.index {
+                        ListIndex::Single(index) => index
+                            .iter()
+                            .filter_map(|index| items.items.get(*index))
+                            .map(items.key)
+                            .collect(),
+                        ListIndex::Multi(indices) => indices
+                            .iter()
+                            .filter_map(|index| items.items.get(*index))
+                            .map(items.key)
+                            .collect::<HashSet<_>>(),
+                    };
+                    if selected_keys != self.selected_keys {
+                        self.selected_keys = selected_keys;
+                        let selected = items
+                            .items
+                            .iter()
+                            .filter(|item| self.selected_keys.contains(&(items.key)(item)))
+                            .cloned()
+                            .collect();
+                        self.props.onchange.emit(selected);
+                    }
+                }
+                self.props.onselected.emit(detail);
+            }
+            Msg::Reorder(reorder) => self.props.onreorder.emit(reorder),
+            Msg::EndReached => {
+                if !self.props.loading {
+                    self.props.onendreached.emit(());
+                }
+            }
+        }
         false
     }
 
     fn change(&mut self, props: Self::Properties) -> bool {
+        self.loading_finished |= self.props.loading && !props.loading;
         self.props = props;
         true
     }
@@ -141,7 +313,8 @@ impl Component for MatList {
                 noninteractive=bool_to_option(self.props.noninteractive)
                 ref=self.node_ref.clone()
             >
-              { self.props.children.clone() }
+              { self.view_items() }
+              { self.view_end() }
             </mwc-list>
         }
     }
@@ -149,55 +322,244 @@ impl Component for MatList {
     fn rendered(&mut self, _first_render: bool) {
         let list = self.node_ref.cast::<List>().unwrap();
         if self.selected_listener.is_none() {
-            let onselected = self.props.onselected.clone();
+            let link = self.link.clone();
+            let syncing = Rc::clone(&self.syncing);
             self.selected_listener = Some(EventListener::new(&list, "selected", move |event| {
+                if syncing.get() {
+                    return;
+                }
                 let val = SelectedDetail::from(event_into_details(event));
-                onselected.emit(val);
+                link.send_message(Msg::Selected(val));
             }));
         }
 
         if self.action_listener.is_none() {
             let onaction = self.props.onaction.clone();
+            let list = self.node_ref.cast::<List>().unwrap();
             self.action_listener = Some(EventListener::new(&list.clone(), "action", move |_| {
                 let val: JsValue = list.index();
                 let index = ListIndex::from(val);
                 onaction.emit(index);
             }));
         }
+
+        if !self.props.reorderable {
+            self.reorderable = None;
+        } else if self.reorderable.is_none() {
+            self.reorderable = Some(Reorderable::new(&list, self.link.callback(Msg::Reorder)));
+        }
+
+        self.observe_end();
+        self.reconcile_selected();
+    }
+}
+
+impl<T: Clone + 'static> MatList<T> {
+    /// Selects the controlled selection on the element if there is one and it
+    /// differs from the element's `index`
+    fn reconcile_selected(&self) {
+        if self.controlled_index().is_none() {
+            return;
+        }
+        let list = match self.node_ref.cast::<List>() {
+            Some(list) => list,
+            None => return,
+        };
+        let link = self.link.clone();
+        let syncing = Rc::clone(&self.syncing);
+        // `select` does nothing until the element has finished its first
+        // update
+        spawn_local(async move {
+            update_complete(&list).await;
+            // the props may have changed in the meantime
+            let selected = match link
+                .get_component()
+                .and_then(|component| component.controlled_index())
+            {
+                Some(selected) => selected,
+                None => return,
+            };
+            if ListIndex::from(list.index()) != selected {
+                syncing.set(true);
+                list.select(&JsValue::from(&selected));
+                syncing.set(false);
+            }
+        });
+    }
+
+    /// The selection controlled by `selected` or `selected_keys`
+    fn controlled_index(&self) -> Option<ListIndex> {
+        let items = match self.props.items.as_ref() {
+            Some(items) => items,
+            None => return self.props.selected.clone(),
+        };
+        let keys = self.props.selected_keys.as_ref()?;
+        let mut indices = items
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| keys.contains(&(items.key)(item)))
+            .map(|(index, _)| index);
+        Some(if self.props.multi {
+            ListIndex::Multi(indices.collect())
+        } else {
+            ListIndex::Single(indices.next())
+        })
+    }
+
+    /// Renders the sentinel observed for `infinite_scroll` and the loading row
+    /// after the items. `mwc-list` only treats list items as its items.
+    fn view_end(&self) -> Html {
+        let sentinel = if self.props.infinite_scroll {
+            html! { <div style="height: 1px;" aria-hidden="true" ref=self.sentinel_ref.clone()></div> }
+        } else {
+            html! {}
+        };
+        let loading = match self.props.loading_indicator {
+            Some(indicator) if self.props.loading => view_loading_indicator(indicator),
+            _ => html! {},
+        };
+        html! { <>{ sentinel }{ loading }</> }
+    }
+
+    fn observe_end(&mut self) {
+        let loading_finished = std::mem::take(&mut self.loading_finished);
+        let sentinel = match self.sentinel_ref.cast::<Element>() {
+            Some(sentinel) if self.props.infinite_scroll => sentinel,
+            _ => {
+                self.end_observer = None;
+                return;
+            }
+        };
+        match &self.end_observer {
+            Some((_, threshold)) if *threshold != self.props.end_threshold => {}
+            Some((observer, _)) => {
+                if loading_finished {
+                    observer.refresh();
+                }
+                return;
+            }
+            None => {}
+        }
+        let observer = EndObserver::new(
+            sentinel,
+            self.props.end_threshold,
+            self.link.callback(|_| Msg::EndReached),
+        );
+        self.end_observer = Some((observer, self.props.end_threshold));
+    }
+
+    fn view_items(&self) -> Html {
+        match self.props.items.as_ref() {
+            Some(items) => items
+                .items
+                .iter()
+                .map(|item| {
+                    // components can't be keyed from here so each item is
+                    // wrapped in a keyed fragment
+                    Html::VList(VList::new_with_children(
+                        vec![(items.render)(item)],
+                        Some((items.key)(item)),
+                    ))
+                })
+                .collect(),
+            None => self.props.children.iter().collect(),
+        }
+    }
+}
+
+fn view_loading_indicator(indicator: LoadingIndicator) -> Html {
+    match indicator {
+        #[cfg(feature = "circular-progress")]
+        LoadingIndicator::Circular => html! {
+            <div style="display: flex; justify-content: center; padding: 8px 0;">
+                <MatCircularProgress indeterminate=true />
+            </div>
+        },
+        #[cfg(feature = "linear-progress")]
+        LoadingIndicator::Linear => html! {
+            <MatLinearProgress indeterminate=true />
+        },
     }
 }
 
-impl WeakComponentLink<MatList> {
+impl<T: Clone + 'static> WeakComponentLink<MatList<T>> {
+    fn list_element(&self) -> List {
+        self.borrow()
+            .as_ref()
+            .unwrap()
+            .get_component()
+            .unwrap()
+            .node_ref
+            .cast::<List>()
+            .unwrap()
+    }
+
     /// Binds to `toggle` method.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/list#methods) for details
     pub fn toggle(&self, index: usize, force: bool) {
-        let list = (*self.borrow().as_ref().unwrap().get_component().unwrap())
-            .node_ref
-            .cast::<List>()
-            .unwrap();
-        list.toggle(index, force)
+        self.list_element().toggle(index, force)
     }
 
     /// Binds to `getFocusedItemIndex` method.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/list#methods) for details
     pub fn get_focused_item_index(&self) -> usize {
-        (*self.borrow().as_ref().unwrap().get_component().unwrap())
-            .node_ref
-            .cast::<List>()
-            .unwrap()
-            .get_focused_item_index()
+        self.list_element().get_focused_item_index()
     }
 
     /// Binds to `focusItemAtIndex` method.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/list#methods) for details
     pub fn focus_item_at_index(&self, index: usize) {
-        (*self.borrow().as_ref().unwrap().get_component().unwrap())
-            .node_ref
-            .cast::<List>()
-            .unwrap()
-            .focus_item_at_index(index)
+        self.list_element().focus_item_at_index(index)
+    }
+
+    /// Binds to `select` method.
+    ///
+    /// Passing an empty index clears the selection.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/list#methods) for details
+    pub fn select(&self, index: &ListIndex) {
+        self.list_element().select(&JsValue::from(index))
+    }
+
+    /// Binds to `layout` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/list#methods) for details
+    pub fn layout(&self, update_items: bool) {
+        self.list_element().layout(update_items)
+    }
+
+    /// Getter for `items`: the `mwc-list-item`s of the list
+    pub fn items(&self) -> Vec<HtmlElement> {
+        self.list_element()
+            .items()
+            .iter()
+            .map(JsCast::unchecked_into)
+            .collect()
+    }
+
+    /// Getter for `selected`: the selected items.
+    ///
+    /// Unless the list is `multi`, there is at most one item.
+    pub fn selected(&self) -> Vec<HtmlElement> {
+        let selected = self.list_element().selected();
+        if selected.is_null() || selected.is_undefined() {
+            Vec::new()
+        } else if js_sys::Array::is_array(&selected) {
+            js_sys::Array::from(&selected)
+                .iter()
+                .map(JsCast::unchecked_into)
+                .collect()
+        } else {
+            vec![selected.unchecked_into()]
+        }
+    }
+
+    /// Getter for `index`: the index of the selected items
+    pub fn index(&self) -> ListIndex {
+        ListIndex::from(self.list_element().index())
     }
 }
@@ -40,6 +40,8 @@ pub struct CheckListItemProps {
     #[prop_or_default]
     pub disabled: bool,
     #[prop_or_default]
+    pub selected: bool,
+    #[prop_or_default]
     pub on_request_selected: Callback<RequestSelectedDetail>,
     pub children: Children,
 }
@@ -73,6 +75,7 @@ impl Component for MatCheckListItem {
                 left=bool_to_option(self.props.left)
                 graphic=self.props.graphic.to_cow_string()
                 disabled=self.props.disabled
+                selected=self.props.selected
                 ref=self.node_ref.clone()
             >{ self.props.children.clone() }</mwc-check-list-item>
         }
@@ -0,0 +1,75 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+use yew::Callback;
+
+/// Observes a sentinel element placed after the items of a list and emits
+/// `callback` whenever it comes within `threshold` pixels of the visible area
+/// of the scrolling container of the list.
+pub(crate) struct EndObserver {
+    observer: IntersectionObserver,
+    sentinel: Element,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl EndObserver {
+    pub(crate) fn new(sentinel: Element, threshold: u32, callback: Callback<()>) -> Self {
+        let closure = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+            let reached = entries.iter().any(|entry| {
+                entry
+                    .unchecked_into::<IntersectionObserverEntry>()
+                    .is_intersecting()
+            });
+            if reached {
+                callback.emit(());
+            }
+        }) as Box<dyn FnMut(js_sys::Array)>);
+
+        let options = IntersectionObserverInit::new();
+        options.set_root(scroll_container(&sentinel).as_ref());
+        options.set_root_margin(&format!("0px 0px {}px 0px", threshold));
+        let observer =
+            IntersectionObserver::new_with_options(closure.as_ref().unchecked_ref(), &options)
+                .unwrap();
+        observer.observe(&sentinel);
+
+        Self {
+            observer,
+            sentinel,
+            _callback: closure,
+        }
+    }
+
+    /// Makes the observer report the current state of the sentinel again, even
+    /// if it hasn't changed.
+    pub(crate) fn refresh(&self) {
+        self.observer.unobserve(&self.sentinel);
+        self.observer.observe(&self.sentinel);
+    }
+}
+
+impl Drop for EndObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Finds the closest ancestor of `element` which scrolls vertically.
+///
+/// `None` means the list scrolls with the document.
+fn scroll_container(element: &Element) -> Option<Element> {
+    let window = web_sys::window()?;
+    let mut parent = element.parent_element();
+    while let Some(element) = parent {
+        let overflow = window
+            .get_computed_style(&element)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value("overflow-y").ok());
+        if matches!(overflow.as_deref(), Some("auto") | Some("scroll")) {
+            return Some(element);
+        }
+        parent = element.parent_element();
+    }
+    None
+}
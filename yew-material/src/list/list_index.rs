@@ -4,7 +4,7 @@ use wasm_bindgen::{JsCast, JsValue};
 /// The `MWCListIndex` type
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/master/packages/list#mwc-list-1)
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ListIndex {
     /// Provided when `multi` prop is set to `true` on the component
     ///
@@ -37,3 +37,45 @@ impl From<JsValue> for ListIndex {
         }
     }
 }
+
+impl From<usize> for ListIndex {
+    fn from(index: usize) -> Self {
+        ListIndex::Single(Some(index))
+    }
+}
+
+impl From<Option<usize>> for ListIndex {
+    fn from(index: Option<usize>) -> Self {
+        ListIndex::Single(index)
+    }
+}
+
+impl From<HashSet<usize>> for ListIndex {
+    fn from(indices: HashSet<usize>) -> Self {
+        ListIndex::Multi(indices)
+    }
+}
+
+/// Converts the index to the representation expected by `mwc-list`: a number
+/// (`-1` for no selection) or a `Set` of numbers
+impl From<&ListIndex> for JsValue {
+    fn from(index: &ListIndex) -> Self {
+        match index {
+            ListIndex::Single(Some(index)) => JsValue::from(*index as f64),
+            ListIndex::Single(None) => JsValue::from(-1),
+            ListIndex::Multi(indices) => {
+                let set = js_sys::Set::new(&JsValue::UNDEFINED);
+                for index in indices {
+                    set.add(&JsValue::from(*index as f64));
+                }
+                set.into()
+            }
+        }
+    }
+}
+
+impl From<ListIndex> for JsValue {
+    fn from(index: ListIndex) -> Self {
+        JsValue::from(&index)
+    }
+}
@@ -56,6 +56,9 @@ pub struct ListItemProps {
     pub noninteractive: bool,
     #[prop_or_default]
     pub selected: bool,
+    /// Hides the item while keeping it in the list
+    #[prop_or_default]
+    pub hidden: bool,
     /// Binds to `request-selected` event on `mwc-list-item`.
     #[prop_or_default]
     pub on_request_selected: Callback<RequestSelectedDetail>,
@@ -99,6 +102,7 @@ impl Component for MatListItem {
                 hasMeta=bool_to_option(self.props.has_meta)
                 noninteractive=bool_to_option(self.props.noninteractive)
                 selected=self.props.selected
+                style=self.props.hidden.then(|| Cow::from("display: none;"))
                 ref=self.node_ref.clone()
             >{ self.props.children.clone() }</mwc-list-item>
         }
@@ -0,0 +1,34 @@
+use std::rc::Rc;
+use yew::virtual_dom::Key;
+use yew::Html;
+
+/// Data to render the items of a [`MatList`][crate::MatList] from
+///
+/// `key` must return a key which uniquely identifies an item. It is used to
+/// preserve the rendered items, and their selection, across updates.
+pub struct ListItems<T> {
+    pub items: Rc<[T]>,
+    pub key: fn(&T) -> Key,
+    pub render: fn(&T) -> Html,
+}
+
+impl<T> ListItems<T> {
+    pub fn new(items: impl Into<Rc<[T]>>, key: fn(&T) -> Key, render: fn(&T) -> Html) -> Self {
+        Self {
+            items: items.into(),
+            key,
+            render,
+        }
+    }
+}
+
+// derived `Clone` would require `T: Clone`
+impl<T> Clone for ListItems<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: Rc::clone(&self.items),
+            key: self.key,
+            render: self.render,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::borrow::Cow;
+
+/// An option of a data-driven [`MatSelect`][crate::MatSelect],
+/// [`MatMultiSelect`][crate::MatMultiSelect] or
+/// [`MatMenuButton`][crate::MatMenuButton]
+///
+/// Each option is rendered as a `MatListItem` showing `label`. Choosing it
+/// emits a clone of `value`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListOption<T> {
+    pub value: T,
+    pub label: Cow<'static, str>,
+    pub disabled: bool,
+}
+
+impl<T> ListOption<T> {
+    /// Creates a new, enabled `ListOption`
+    pub fn new(value: T, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            disabled: false,
+        }
+    }
+}
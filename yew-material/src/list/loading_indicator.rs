@@ -0,0 +1,14 @@
+/// Progress indicator shown in the loading row of a [`MatList`][crate::MatList]
+///
+/// Each variant is only available with the feature of its progress component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoadingIndicator {
+    /// An indeterminate [`MatCircularProgress`][crate::MatCircularProgress],
+    /// centered in the row. Needs the `circular-progress` feature.
+    #[cfg(feature = "circular-progress")]
+    Circular,
+    /// An indeterminate [`MatLinearProgress`][crate::MatLinearProgress]
+    /// spanning the width of the list. Needs the `linear-progress` feature.
+    #[cfg(feature = "linear-progress")]
+    Linear,
+}
@@ -0,0 +1,282 @@
+use super::List;
+use gloo::events::{EventListener, EventListenerOptions, EventListenerPhase};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    DragEvent, Element, Event, HtmlElement, KeyboardEvent, MutationObserver, MutationObserverInit,
+};
+use yew::Callback;
+
+const INDICATOR_BEFORE: &str = "inset 0 2px 0 0 var(--mdc-theme-primary, #6200ee)";
+const INDICATOR_AFTER: &str = "inset 0 -2px 0 0 var(--mdc-theme-primary, #6200ee)";
+
+/// State of an ongoing reorder, shared between the listeners
+#[derive(Default)]
+struct Reorder {
+    /// Index of the dragged (or lifted) item
+    from: Option<usize>,
+    /// Index the dragged item would have if it was dropped now
+    to: usize,
+    dragged: Option<HtmlElement>,
+    indicator: Option<HtmlElement>,
+}
+
+impl Reorder {
+    fn start(&mut self, index: usize, item: HtmlElement) {
+        self.clear();
+        let _ = item.style().set_property("opacity", "0.6");
+        self.from = Some(index);
+        self.to = index;
+        self.dragged = Some(item);
+    }
+
+    /// Moves the drop target to the position right before (or after) `item`
+    /// which is at `index`
+    fn target(&mut self, index: usize, item: HtmlElement, after: bool) {
+        let from = match self.from {
+            Some(from) => from,
+            None => return,
+        };
+        let insert_at = if after { index + 1 } else { index };
+        self.to = if insert_at > from {
+            insert_at - 1
+        } else {
+            insert_at
+        };
+
+        if let Some(indicator) = self.indicator.take() {
+            let _ = indicator.style().remove_property("box-shadow");
+        }
+        if self.to != from {
+            let shadow = if after {
+                INDICATOR_AFTER
+            } else {
+                INDICATOR_BEFORE
+            };
+            let _ = item.style().set_property("box-shadow", shadow);
+            self.indicator = Some(item);
+        }
+    }
+
+    /// Ends the reorder, returning `(from, to)` if the item has moved
+    fn finish(&mut self) -> Option<(usize, usize)> {
+        let reorder = self
+            .from
+            .filter(|from| *from != self.to)
+            .map(|from| (from, self.to));
+        self.clear();
+        reorder
+    }
+
+    fn clear(&mut self) {
+        if let Some(dragged) = self.dragged.take() {
+            let _ = dragged.style().remove_property("opacity");
+        }
+        if let Some(indicator) = self.indicator.take() {
+            let _ = indicator.style().remove_property("box-shadow");
+        }
+        self.from = None;
+    }
+}
+
+/// Reordering of the items of a list, with the mouse and with the keyboard.
+///
+/// With the keyboard, `Space` lifts the focused item, the arrow keys move it,
+/// `Space` or `Enter` drops it and `Escape` cancels. `Space` toggles check and
+/// radio items, so those are lifted with `Alt` + `ArrowUp`/`ArrowDown`, which
+/// lifts any item and moves it right away.
+///
+/// Items are made draggable as they are added to the list, and aren't anymore
+/// once this is dropped.
+pub(crate) struct Reorderable {
+    list: Element,
+    _listeners: Vec<EventListener>,
+    observer: MutationObserver,
+    _callback: Closure<dyn FnMut()>,
+}
+
+impl Reorderable {
+    pub(crate) fn new(list: &List, onreorder: Callback<(usize, usize)>) -> Self {
+        let element: Element = JsValue::clone(list).unchecked_into();
+        set_items_draggable(&element, true);
+
+        let callback = {
+            let element = element.clone();
+            Closure::wrap(Box::new(move || set_items_draggable(&element, true)) as Box<dyn FnMut()>)
+        };
+        let observer = MutationObserver::new(callback.as_ref().unchecked_ref()).unwrap();
+        let options = MutationObserverInit::new();
+        options.set_child_list(true);
+        let _ = observer.observe_with_options(&element, &options);
+
+        Self {
+            list: element,
+            _listeners: reorder_listeners(list, onreorder),
+            observer,
+            _callback: callback,
+        }
+    }
+}
+
+impl Drop for Reorderable {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+        set_items_draggable(&self.list, false);
+    }
+}
+
+/// Makes the items of `list` draggable
+fn set_items_draggable(list: &Element, draggable: bool) {
+    let children = list.children();
+    for index in 0..children.length() {
+        if let Some(child) = children.item(index) {
+            let item =
+                child.local_name().ends_with("list-item") && !child.has_attribute("noninteractive");
+            if draggable && item {
+                let _ = child.set_attribute("draggable", "true");
+            } else {
+                let _ = child.remove_attribute("draggable");
+            }
+        }
+    }
+}
+
+/// Listeners implementing reordering of the items of `list`
+fn reorder_listeners(list: &List, onreorder: Callback<(usize, usize)>) -> Vec<EventListener> {
+    let state = Rc::new(RefCell::new(Reorder::default()));
+    let options = EventListenerOptions::enable_prevent_default();
+
+    let dragstart = {
+        let state = Rc::clone(&state);
+        let element: List = JsValue::clone(list).unchecked_into();
+        EventListener::new(list, "dragstart", move |event| {
+            if let Some((index, item)) = item_of_event(&element, event) {
+                if let Some(data_transfer) = event.unchecked_ref::<DragEvent>().data_transfer() {
+                    data_transfer.set_effect_allowed("move");
+                    // Firefox doesn't start dragging without data
+                    let _ = data_transfer.set_data("text/plain", "");
+                }
+                state.borrow_mut().start(index, item);
+            }
+        })
+    };
+
+    let dragover = {
+        let state = Rc::clone(&state);
+        let element: List = JsValue::clone(list).unchecked_into();
+        EventListener::new_with_options(list, "dragover", options, move |event| {
+            if state.borrow().from.is_none() {
+                return;
+            }
+            if let Some((index, item)) = item_of_event(&element, event) {
+                event.prevent_default();
+                let rect = item.get_bounding_client_rect();
+                let y = f64::from(event.unchecked_ref::<DragEvent>().client_y());
+                let after = y > rect.top() + rect.height() / 2.0;
+                state.borrow_mut().target(index, item, after);
+            }
+        })
+    };
+
+    let drop = {
+        let state = Rc::clone(&state);
+        let onreorder = onreorder.clone();
+        EventListener::new_with_options(list, "drop", options, move |event| {
+            let reorder = state.borrow_mut().finish();
+            if let Some(reorder) = reorder {
+                event.prevent_default();
+                onreorder.emit(reorder);
+            }
+        })
+    };
+
+    let dragend = {
+        let state = Rc::clone(&state);
+        EventListener::new(list, "dragend", move |_| {
+            state.borrow_mut().clear();
+        })
+    };
+
+    // runs in the capture phase so that `mwc-list` doesn't handle the keys
+    // used for reordering
+    let keydown = {
+        let element: List = JsValue::clone(list).unchecked_into();
+        let options = EventListenerOptions {
+            phase: EventListenerPhase::Capture,
+            passive: false,
+        };
+        EventListener::new_with_options(list, "keydown", options, move |event| {
+            let (index, item) = match item_of_event(&element, event) {
+                Some(item) => item,
+                None => return,
+            };
+            let event_ref = event.unchecked_ref::<KeyboardEvent>();
+            let key = event_ref.key();
+            let mut state = state.borrow_mut();
+
+            let from = match state.from {
+                Some(from) => from,
+                None if event_ref.alt_key() && (key == "ArrowUp" || key == "ArrowDown") => {
+                    state.start(index, item);
+                    index
+                }
+                None if key == " " && item.local_name() == "mwc-list-item" => {
+                    consume(event);
+                    state.start(index, item);
+                    return;
+                }
+                None => return,
+            };
+
+            match key.as_str() {
+                "ArrowUp" | "ArrowDown" => {
+                    consume(event);
+                    let count = element.items().length() as usize;
+                    let to = if key == "ArrowUp" {
+                        state.to.saturating_sub(1)
+                    } else {
+                        (state.to + 1).min(count.saturating_sub(1))
+                    };
+                    if let Ok(target) = element.items().get(to as u32).dyn_into::<HtmlElement>() {
+                        // the indicator is drawn on the side of the target
+                        // item the dragged item would be placed on
+                        let after = to > from;
+                        state.target(to, target, after);
+                    }
+                }
+                " " | "Enter" => {
+                    consume(event);
+                    if let Some(reorder) = state.finish() {
+                        onreorder.emit(reorder);
+                    }
+                }
+                "Escape" => {
+                    consume(event);
+                    state.clear();
+                }
+                "Tab" => state.clear(),
+                _ => {}
+            }
+        })
+    };
+
+    vec![dragstart, dragover, drop, dragend, keydown]
+}
+
+fn consume(event: &Event) {
+    event.prevent_default();
+    event.stop_propagation();
+}
+
+/// Finds the item of `list` the target of `event` is in, and its index
+fn item_of_event(list: &List, event: &Event) -> Option<(usize, HtmlElement)> {
+    let target = event.target()?.dyn_into::<Element>().ok()?;
+    let item = target.closest("[mwc-list-item]").ok()??;
+    let index = list.items().index_of(&item, 0);
+    if index < 0 {
+        return None;
+    }
+    Some((index as usize, item.unchecked_into()))
+}
@@ -0,0 +1,323 @@
+use super::List;
+use crate::list::{ListIndex, SelectedDetail};
+use crate::{bool_to_option, event_into_details};
+use gloo::events::EventListener;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::virtual_dom::{Key, VList};
+
+/// Height of the items of a [`MatVirtualList`]
+///
+/// Every item of the list must have the same height.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemHeight {
+    /// Height of a single line `mwc-list-item` (48px)
+    SingleLine,
+    /// Height of a `mwc-list-item` with `twoline` set (72px)
+    TwoLine,
+    /// A fixed height in pixels
+    Fixed(u32),
+}
+
+impl ItemHeight {
+    fn to_px(self) -> f64 {
+        match self {
+            ItemHeight::SingleLine => 48.0,
+            ItemHeight::TwoLine => 72.0,
+            ItemHeight::Fixed(px) => f64::from(px),
+        }
+    }
+}
+
+/// Owned function rendering the item at the given index of a
+/// [`MatVirtualList`]
+#[derive(Clone)]
+pub struct ItemRenderer(pub(crate) Rc<dyn Fn(usize) -> Html>);
+
+/// A `mwc-list` which only renders the items that are visible
+///
+/// Items are rendered by `render_item` on demand, as the list is scrolled. Only
+/// the items in the viewport plus `overscan` items on either side of it are
+/// rendered at any time.
+///
+/// Selection is tracked by the component so all the indices it reports are
+/// indices in the whole list, not in the rendered items.
+pub struct MatVirtualList {
+    props: VirtualListProps,
+    link: ComponentLink<Self>,
+    viewport_ref: NodeRef,
+    list_ref: NodeRef,
+    scroll_top: f64,
+    viewport_height: f64,
+    selected: ListIndex,
+    scroll_listener: Option<EventListener>,
+    action_listener: Option<EventListener>,
+    selected_listener: Option<EventListener>,
+}
+
+/// Props for [`MatVirtualList`]
+#[derive(Properties, Clone)]
+pub struct VirtualListProps {
+    #[prop_or_default]
+    pub classes: Classes,
+    /// CSS height of the scrolling viewport
+    #[prop_or(Cow::from("400px"))]
+    pub height: Cow<'static, str>,
+    pub item_count: usize,
+    #[prop_or(ItemHeight::SingleLine)]
+    pub item_height: ItemHeight,
+    /// Number of items rendered before and after the visible ones
+    #[prop_or(5)]
+    pub overscan: usize,
+    /// Renders the item at the given index.
+    ///
+    /// Use [`MatVirtualList::item_renderer`] to create it.
+    pub render_item: ItemRenderer,
+    #[prop_or_default]
+    pub activatable: bool,
+    #[prop_or_default]
+    pub multi: bool,
+    #[prop_or_default]
+    pub wrap_focus: bool,
+    /// Emits the selected indices whenever the selection changes
+    #[prop_or_default]
+    pub onselected: Callback<ListIndex>,
+    /// Binds to `action` event on `mwc-list`.
+    ///
+    /// The selected indices are passed to the callback.
+    #[prop_or_default]
+    pub onaction: Callback<ListIndex>,
+}
+
+pub enum Msg {
+    Scroll,
+    Selected(ListIndex),
+    Action,
+}
+
+impl Component for MatVirtualList {
+    type Message = Msg;
+    type Properties = VirtualListProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        List::ensure_loaded();
+        let selected = empty_index(props.multi);
+        Self {
+            props,
+            link,
+            viewport_ref: NodeRef::default(),
+            list_ref: NodeRef::default(),
+            scroll_top: 0.0,
+            viewport_height: 0.0,
+            selected,
+            scroll_listener: None,
+            action_listener: None,
+            selected_listener: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Scroll => {
+                let window = self.window();
+                self.measure();
+                self.window() != window
+            }
+            Msg::Selected(index) => {
+                let selected = self.to_logical(index);
+                if selected != self.selected {
+                    self.selected = selected.clone();
+                    self.props.onselected.emit(selected);
+                }
+                false
+            }
+            Msg::Action => {
+                self.props.onaction.emit(self.selected.clone());
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        if self.props.multi != props.multi {
+            self.selected = empty_index(props.multi);
+        } else {
+            self.selected = retain_index(&self.selected, |index| index < props.item_count);
+        }
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let item_height = self.props.item_height.to_px();
+        let window = self.window();
+        let total_height = self.props.item_count as f64 * item_height;
+        let offset = window.start as f64 * item_height;
+
+        html! {
+            <div
+                class=self.props.classes.clone()
+                style=format!("height: {}; overflow-y: auto;", self.props.height)
+                ref=self.viewport_ref.clone()
+            >
+                <div style=format!("position: relative; height: {}px;", total_height)>
+                    <mwc-list
+                        style=format!(
+                            "position: absolute; top: {}px; left: 0; right: 0; --mdc-list-vertical-padding: 0px;",
+                            offset
+                        )
+                        activatable=bool_to_option(self.props.activatable)
+                        multi=bool_to_option(self.props.multi)
+                        wrapFocus=bool_to_option(self.props.wrap_focus)
+                        ref=self.list_ref.clone()
+                    >
+                        { for window.map(|index| {
+                            // each element keeps rendering the same index, so
+                            // its focus and selection stay with it
+                            Html::VList(VList::new_with_children(
+                                vec![(self.props.render_item.0)(index)],
+                                Some(Key::from(index)),
+                            ))
+                        }) }
+                    </mwc-list>
+                </div>
+            </div>
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            // the window can only be computed once the viewport has a size
+            self.link.send_message(Msg::Scroll);
+        }
+
+        let viewport = self.viewport_ref.cast::<Element>().unwrap();
+        if self.scroll_listener.is_none() {
+            let link = self.link.clone();
+            self.scroll_listener = Some(EventListener::new(&viewport, "scroll", move |_| {
+                link.send_message(Msg::Scroll)
+            }));
+        }
+
+        let list = self.list_ref.cast::<List>().unwrap();
+        if self.selected_listener.is_none() {
+            let link = self.link.clone();
+            self.selected_listener = Some(EventListener::new(&list, "selected", move |event| {
+                let detail = SelectedDetail::from(event_into_details(event));
+                link.send_message(Msg::Selected(detail.index))
+            }));
+        }
+
+        if self.action_listener.is_none() {
+            let link = self.link.clone();
+            self.action_listener = Some(EventListener::new(&list, "action", move |_| {
+                link.send_message(Msg::Action)
+            }));
+        }
+
+        // the rendered items may have been reused for other indices so the
+        // selection of the list is brought in line with the logical one
+        let rendered = self.to_rendered();
+        if ListIndex::from(list.index()) != rendered {
+            list.select(&JsValue::from(&rendered));
+        }
+    }
+}
+
+impl MatVirtualList {
+    /// Returns [`ItemRenderer`] to be passed to `render_item` prop
+    pub fn item_renderer<F: Fn(usize) -> Html + 'static>(func: F) -> ItemRenderer {
+        ItemRenderer(Rc::new(func))
+    }
+
+    fn measure(&mut self) {
+        if let Some(viewport) = self.viewport_ref.cast::<Element>() {
+            self.scroll_top = f64::from(viewport.scroll_top());
+            self.viewport_height = f64::from(viewport.client_height());
+        }
+    }
+
+    /// Range of the indices of the rendered items
+    fn window(&self) -> Range<usize> {
+        let item_height = self.props.item_height.to_px();
+        let first_visible = (self.scroll_top / item_height).floor() as usize;
+        let visible_count = (self.viewport_height / item_height).ceil() as usize + 1;
+
+        let end = (first_visible + visible_count + self.props.overscan).min(self.props.item_count);
+        let start = first_visible.saturating_sub(self.props.overscan).min(end);
+        start..end
+    }
+
+    /// Converts the selection reported by `mwc-list`, which is relative to
+    /// the rendered items, to indices in the whole list
+    fn to_logical(&self, index: ListIndex) -> ListIndex {
+        let window = self.window();
+        match (index, &self.selected) {
+            (ListIndex::Multi(indices), selected) => {
+                let outside_window = match selected {
+                    ListIndex::Multi(selected) => selected
+                        .iter()
+                        .copied()
+                        .filter(|index| !window.contains(index))
+                        .collect(),
+                    ListIndex::Single(_) => HashSet::new(),
+                };
+                ListIndex::Multi(
+                    indices
+                        .into_iter()
+                        .map(|index| index + window.start)
+                        .chain(outside_window)
+                        .collect(),
+                )
+            }
+            (ListIndex::Single(Some(index)), _) => ListIndex::Single(Some(index + window.start)),
+            // a selected item which is not rendered can't be deselected by `mwc-list`
+            (ListIndex::Single(None), ListIndex::Single(Some(selected)))
+                if !window.contains(selected) =>
+            {
+                ListIndex::Single(Some(*selected))
+            }
+            (ListIndex::Single(None), _) => ListIndex::Single(None),
+        }
+    }
+
+    /// Converts the selection to indices relative to the rendered items
+    fn to_rendered(&self) -> ListIndex {
+        let window = self.window();
+        match retain_index(&self.selected, |index| window.contains(&index)) {
+            ListIndex::Single(index) => ListIndex::Single(index.map(|index| index - window.start)),
+            ListIndex::Multi(indices) => ListIndex::Multi(
+                indices
+                    .into_iter()
+                    .map(|index| index - window.start)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn empty_index(multi: bool) -> ListIndex {
+    if multi {
+        ListIndex::Multi(HashSet::new())
+    } else {
+        ListIndex::Single(None)
+    }
+}
+
+fn retain_index(index: &ListIndex, predicate: impl Fn(usize) -> bool) -> ListIndex {
+    match index {
+        ListIndex::Single(index) => ListIndex::Single(index.filter(|index| predicate(*index))),
+        ListIndex::Multi(indices) => ListIndex::Multi(
+            indices
+                .iter()
+                .copied()
+                .filter(|index| predicate(*index))
+                .collect(),
+        ),
+    }
+}
@@ -2,6 +2,15 @@ mod models;
 
 pub use models::*;
 
+mod anchor;
+pub(crate) use anchor::Anchor;
+
+mod context_menu;
+pub use context_menu::{ContextMenuAction, ContextMenuProps, MatContextMenu};
+
+mod sub_menu;
+pub use sub_menu::{MatSubMenu, SubMenuProps};
+
 use crate::list::{ListIndex, SelectedDetail};
 use crate::{bool_to_option, event_into_details, to_option_string, WeakComponentLink};
 use gloo::events::EventListener;
@@ -22,15 +31,11 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     fn index(this: &Menu) -> JsValue;
 
-    #[wasm_bindgen(method)]
-    fn show(this: &Menu);
-
     #[wasm_bindgen(method)]
     fn close(this: &Menu);
 
-    // `MWCMenuIndex` is completely undocumented
     #[wasm_bindgen(method)]
-    fn select(this: &Menu, index: &JsValue) -> usize;
+    fn select(this: &Menu, index: &JsValue);
 
     #[wasm_bindgen(method, js_name = getFocusedItemIndex)]
     fn get_focused_item_index(this: &Menu) -> usize;
@@ -54,6 +59,7 @@ pub struct MatMenu {
     closed_listener: Option<EventListener>,
     action_listener: Option<EventListener>,
     selected_listener: Option<EventListener>,
+    anchor: Anchor,
 }
 
 /// Props for `MatMenu`
@@ -69,8 +75,12 @@ pub struct MenuProps {
     pub classes: Classes,
     #[prop_or_default]
     pub open: bool,
+    /// The element the menu is positioned relative to.
+    ///
+    /// It is applied again whenever it changes, or whenever the element a
+    /// `NodeRef` anchor refers to changes.
     #[prop_or_default]
-    pub anchor: Option<web_sys::HtmlElement>,
+    pub anchor: Option<MenuAnchor>,
     #[prop_or(Corner::TopStart)]
     pub corner: Corner,
     #[prop_or(MenuCorner::Start)]
@@ -122,8 +132,9 @@ pub struct MenuProps {
     /// `WeakComponentLink` for `MatMenu` which provides the following methods
     /// - `get_focused_item_index(&self) -> usize`
     /// - `focus_item_at_index(&self, index: usize)`
-    /// - `select(&self, index: &JsValue)`
+    /// - `select(&self, index: &ListIndex)`
     /// - `show(&self)`
+    /// - `show_at(&self, anchor: impl Into<MenuAnchor>)`
     /// - `close(&self)`
     ///
     /// See [`WeakComponentLink`](/yew_material/struct.WeakComponentLink.html)
@@ -147,6 +158,7 @@ impl Component for MatMenu {
             closed_listener: None,
             action_listener: None,
             selected_listener: None,
+            anchor: Anchor::default(),
         }
     }
 
@@ -185,13 +197,10 @@ impl Component for MatMenu {
         }
     }
 
-    fn rendered(&mut self, first_render: bool) {
+    fn rendered(&mut self, _first_render: bool) {
+        self.anchor
+            .apply(&self.node_ref, self.props.anchor.as_ref());
         let menu = self.node_ref.cast::<Menu>().unwrap();
-        if first_render {
-            if let Some(anchor) = self.props.anchor.as_ref() {
-                menu.set_anchor(anchor);
-            }
-        }
         if self.opened_listener.is_none() {
             let onopened = self.props.onopened.clone();
             self.opened_listener = Some(EventListener::new(&menu, "opened", move |_| {
@@ -250,27 +259,34 @@ impl WeakComponentLink<MatMenu> {
 
     /// Binds to `select` method.
     ///
-    /// `index` is `JsValue` because `MWCMenuIndex` mentioned in mwc docs is
-    /// completely undocumented.
+    /// `MWCMenuIndex` has the same shape as `MWCListIndex`: a single index, or
+    /// a set of indices when the menu is `multi`.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#methods) for details
-    pub fn select(&self, index: &JsValue) {
+    pub fn select(&self, index: &ListIndex) {
         (*self.borrow().as_ref().unwrap().get_component().unwrap())
             .node_ref
             .cast::<Menu>()
             .unwrap()
-            .select(index);
+            .select(&JsValue::from(index));
     }
 
     /// Binds to `show` method.
     ///
     /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#methods) for details
     pub fn show(&self) {
-        (*self.borrow().as_ref().unwrap().get_component().unwrap())
-            .node_ref
-            .cast::<Menu>()
-            .unwrap()
-            .show();
+        let link = self.borrow();
+        let menu = link.as_ref().unwrap().get_component().unwrap();
+        menu.anchor.show(&menu.node_ref, menu.props.anchor.as_ref());
+    }
+
+    /// Anchors the menu to `anchor` and shows it.
+    ///
+    /// The menu stays anchored to `anchor` until `anchor` prop changes.
+    pub fn show_at(&self, anchor: impl Into<MenuAnchor>) {
+        let link = self.borrow();
+        let menu = link.as_ref().unwrap().get_component().unwrap();
+        Anchor::show_at(&menu.node_ref, anchor.into());
     }
 
     /// Binds to `close` method.
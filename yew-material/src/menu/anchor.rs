@@ -0,0 +1,61 @@
+use super::MenuAnchor;
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+use web_sys::{HtmlElement, Node};
+use yew::NodeRef;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `mwc-menu` or `mwc-menu-surface`
+    #[derive(Debug)]
+    #[wasm_bindgen(extends = Node)]
+    type AnchoredSurface;
+
+    #[wasm_bindgen(method)]
+    fn show(this: &AnchoredSurface);
+
+    #[wasm_bindgen(method, setter)]
+    fn set_anchor(this: &AnchoredSurface, value: Option<&HtmlElement>);
+}
+
+/// The anchor of the `mwc-menu` or `mwc-menu-surface` `surface` refers to
+#[derive(Default)]
+pub(crate) struct Anchor {
+    /// The element `anchor` prop resolved to when it was last applied
+    applied: RefCell<Option<HtmlElement>>,
+}
+
+impl Anchor {
+    /// Sets the anchor of `surface` to the element `anchor` refers to if it
+    /// has changed since it was last applied, or unsets it if `anchor` no
+    /// longer refers to an element
+    pub(crate) fn apply(&self, surface: &NodeRef, anchor: Option<&MenuAnchor>) {
+        let surface = match surface.cast::<AnchoredSurface>() {
+            Some(surface) => surface,
+            None => return,
+        };
+        let anchor = anchor.and_then(|anchor| anchor.element());
+        let mut applied = self.applied.borrow_mut();
+        if anchor != *applied {
+            surface.set_anchor(anchor.as_ref());
+            *applied = anchor;
+        }
+    }
+
+    /// Applies `anchor` and shows `surface`
+    pub(crate) fn show(&self, surface: &NodeRef, anchor: Option<&MenuAnchor>) {
+        // a `NodeRef` anchor may not have been rendered when the surface was
+        self.apply(surface, anchor);
+        surface.cast::<AnchoredSurface>().unwrap().show();
+    }
+
+    /// Anchors `surface` to `anchor` and shows it. It stays anchored to
+    /// `anchor` until the anchor applied with [`Anchor::apply`] changes.
+    pub(crate) fn show_at(surface: &NodeRef, anchor: MenuAnchor) {
+        let surface = surface.cast::<AnchoredSurface>().unwrap();
+        if let Some(anchor) = anchor.element() {
+            surface.set_anchor(Some(&anchor));
+        }
+        surface.show();
+    }
+}
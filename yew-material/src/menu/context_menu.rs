@@ -0,0 +1,268 @@
+use crate::list::ListIndex;
+use crate::{MatMenu, WeakComponentLink};
+use gloo::events::{EventListener, EventListenerOptions, EventListenerPhase};
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// How long a touch has to be held to open the menu, in milliseconds
+const LONG_PRESS_MS: u32 = 500;
+/// How far a touch can move, in pixels, before it stops being a long press
+const LONG_PRESS_SLOP: i32 = 10;
+
+/// Detail of an action in a [`MatContextMenu`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContextMenuAction {
+    /// Index of the activated menu item
+    pub index: ListIndex,
+    /// The `data-context-item` of the target item the menu was opened on, if
+    /// any
+    pub item: Option<String>,
+}
+
+/// A [`MatMenu`] which opens at the pointer when its target area is
+/// right-clicked or long-pressed
+///
+/// The children are the target area. Elements inside it with a
+/// `data-context-item` attribute are target items: the value of the attribute
+/// of the item the menu was opened on is reported by `onopened` and
+/// `onaction`.
+///
+/// The menu closes when the page is scrolled or `Escape` is pressed.
+pub struct MatContextMenu {
+    props: ContextMenuProps,
+    link: ComponentLink<Self>,
+    menu_link: WeakComponentLink<MatMenu>,
+    position: (isize, isize),
+    item: Option<String>,
+    open: bool,
+    /// Position the menu has to be reopened at once it has closed
+    reopen: Option<(isize, isize, Option<String>)>,
+    show: bool,
+    long_press: Option<(i32, i32, Timeout)>,
+    close_listeners: Vec<EventListener>,
+}
+
+/// Props for [`MatContextMenu`]
+#[derive(Properties, Clone)]
+pub struct ContextMenuProps {
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Items of the menu
+    pub menu: Html,
+    #[prop_or_default]
+    pub quick: bool,
+    /// Emits the `data-context-item` of the target item, if any, when the menu
+    /// opens
+    #[prop_or_default]
+    pub onopened: Callback<Option<String>>,
+    #[prop_or_default]
+    pub onclosed: Callback<()>,
+    #[prop_or_default]
+    pub onaction: Callback<ContextMenuAction>,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Open(isize, isize, Option<String>),
+    Opened,
+    Closed,
+    Close,
+    Action(ListIndex),
+    PointerDown(PointerEvent),
+    PointerMove(PointerEvent),
+    PointerUp,
+}
+
+impl Component for MatContextMenu {
+    type Message = Msg;
+    type Properties = ContextMenuProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            menu_link: WeakComponentLink::default(),
+            position: (0, 0),
+            item: None,
+            open: false,
+            reopen: None,
+            show: false,
+            long_press: None,
+            close_listeners: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Open(x, y, item) => {
+                self.long_press = None;
+                if self.open {
+                    // an open menu can't be moved so it is reopened
+                    self.reopen = Some((x, y, item));
+                    self.menu_link.close();
+                    return false;
+                }
+                self.position = (x, y);
+                self.item = item;
+                self.show = true;
+                true
+            }
+            Msg::Opened => {
+                self.open = true;
+                self.listen_for_close();
+                self.props.onopened.emit(self.item.clone());
+                false
+            }
+            Msg::Closed => {
+                self.open = false;
+                self.close_listeners.clear();
+                self.props.onclosed.emit(());
+                if let Some((x, y, item)) = self.reopen.take() {
+                    self.link.send_message(Msg::Open(x, y, item));
+                }
+                false
+            }
+            Msg::Close => {
+                if self.open {
+                    self.menu_link.close();
+                }
+                false
+            }
+            Msg::Action(index) => {
+                self.props.onaction.emit(ContextMenuAction {
+                    index,
+                    item: self.item.clone(),
+                });
+                false
+            }
+            Msg::PointerDown(event) => {
+                if event.pointer_type() != "touch" || in_menu(&event) {
+                    return false;
+                }
+                let (x, y) = (event.client_x(), event.client_y());
+                let item = context_item(&event);
+                let link = self.link.clone();
+                let timeout = Timeout::new(LONG_PRESS_MS, move || {
+                    link.send_message(Msg::Open(x as isize, y as isize, item))
+                });
+                self.long_press = Some((x, y, timeout));
+                false
+            }
+            Msg::PointerMove(event) => {
+                if let Some((x, y, _)) = self.long_press {
+                    let moved = (event.client_x() - x)
+                        .abs()
+                        .max((event.client_y() - y).abs());
+                    if moved > LONG_PRESS_SLOP {
+                        self.long_press = None;
+                    }
+                }
+                false
+            }
+            Msg::PointerUp => {
+                self.long_press = None;
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let oncontextmenu = self.link.batch_callback(|event: MouseEvent| {
+            if in_menu(&event) {
+                return None;
+            }
+            event.prevent_default();
+            Some(Msg::Open(
+                event.client_x() as isize,
+                event.client_y() as isize,
+                context_item(&event),
+            ))
+        });
+        html! {
+            <div
+                class=self.props.classes.clone()
+                oncontextmenu=oncontextmenu
+                onpointerdown=self.link.callback(Msg::PointerDown)
+                onpointermove=self.link.callback(Msg::PointerMove)
+                onpointerup=self.link.callback(|_| Msg::PointerUp)
+                onpointercancel=self.link.callback(|_| Msg::PointerUp)
+            >
+                { self.props.children.clone() }
+                <MatMenu
+                    absolute=true
+                    fixed=true
+                    x=self.position.0
+                    y=self.position.1
+                    quick=self.props.quick
+                    menu_link=self.menu_link.clone()
+                    onopened=self.link.callback(|_| Msg::Opened)
+                    onclosed=self.link.callback(|_| Msg::Closed)
+                    onaction=self.link.callback(Msg::Action)
+                >
+                    { self.props.menu.clone() }
+                </MatMenu>
+            </div>
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        // the menu is shown once it has been rendered at the new position
+        if self.show {
+            self.show = false;
+            self.menu_link.show();
+        }
+    }
+}
+
+impl MatContextMenu {
+    fn listen_for_close(&mut self) {
+        let window = match web_sys::window() {
+            Some(window) => window,
+            None => return,
+        };
+        // scrolling of any element, not only of the document, closes the menu
+        let options = EventListenerOptions {
+            phase: EventListenerPhase::Capture,
+            passive: true,
+        };
+        let link = self.link.clone();
+        let scroll = EventListener::new_with_options(&window, "scroll", options, move |_| {
+            link.send_message(Msg::Close)
+        });
+        let link = self.link.clone();
+        let keydown = EventListener::new(&window, "keydown", move |event| {
+            if event.unchecked_ref::<KeyboardEvent>().key() == "Escape" {
+                link.send_message(Msg::Close)
+            }
+        });
+        self.close_listeners = vec![scroll, keydown];
+    }
+}
+
+/// Whether `event` happened inside of the menu itself rather than the target
+/// area
+fn in_menu(event: &Event) -> bool {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|target| target.closest("mwc-menu").ok().flatten())
+        .is_some()
+}
+
+/// Finds the `data-context-item` of the target item `event` happened in
+fn context_item(event: &Event) -> Option<String> {
+    event
+        .target()?
+        .dyn_into::<Element>()
+        .ok()?
+        .closest("[data-context-item]")
+        .ok()??
+        .get_attribute("data-context-item")
+}
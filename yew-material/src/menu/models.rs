@@ -1,3 +1,6 @@
+use web_sys::HtmlElement;
+use yew::NodeRef;
+
 /// The `Corner` type
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#propertiesattributes)
@@ -22,7 +25,7 @@ impl ToString for Corner {
             BottomLeft => "BOTTOM_LEFT",
             BottomRight => "BOTTOM_RIGHT",
             TopStart => "TOP_START",
-            TopEnd => "TOP_END ",
+            TopEnd => "TOP_END",
             BottomStart => "BOTTOM_START",
             BottomEnd => "BOTTOM_END",
         }
@@ -73,3 +76,35 @@ impl ToString for DefaultFocusState {
         .to_string()
     }
 }
+
+/// The element a [`MatMenu`][crate::MatMenu] is anchored to
+///
+/// A `NodeRef` is resolved when the anchor is applied, so it can refer to an
+/// element rendered in the same `html!` as the menu.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MenuAnchor {
+    Element(HtmlElement),
+    NodeRef(NodeRef),
+}
+
+impl MenuAnchor {
+    /// The element the anchor refers to, if it is rendered
+    pub fn element(&self) -> Option<HtmlElement> {
+        match self {
+            MenuAnchor::Element(element) => Some(element.clone()),
+            MenuAnchor::NodeRef(node_ref) => node_ref.cast::<HtmlElement>(),
+        }
+    }
+}
+
+impl From<HtmlElement> for MenuAnchor {
+    fn from(element: HtmlElement) -> Self {
+        MenuAnchor::Element(element)
+    }
+}
+
+impl From<NodeRef> for MenuAnchor {
+    fn from(node_ref: NodeRef) -> Self {
+        MenuAnchor::NodeRef(node_ref)
+    }
+}
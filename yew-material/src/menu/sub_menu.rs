@@ -0,0 +1,192 @@
+use crate::list::ListIndex;
+use crate::menu::{Corner, DefaultFocusState, MenuAnchor};
+use crate::{bool_to_option, MatIcon, MatMenu, WeakComponentLink};
+use gloo::events::{EventListener, EventListenerOptions};
+use std::borrow::Cow;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Events of the child menu which must not reach the parent menu
+const CONTAINED_EVENTS: [&str; 6] = [
+    "keydown",
+    "action",
+    "selected",
+    "opened",
+    "closed",
+    "request-selected",
+];
+
+/// A list item of a [`MatMenu`] which opens a child menu
+///
+/// The children are the items of the child menu, which opens next to this
+/// item (with [`Corner::TopEnd`]) when it is clicked or when `ArrowRight`,
+/// `Enter` or `Space` is pressed on it. `ArrowLeft` closes the child menu and
+/// gives the focus back to this item.
+///
+/// Events of the child menu don't reach the parent menu. When an item of the
+/// child menu is activated, `onaction` is emitted and every menu up the chain
+/// is closed.
+pub struct MatSubMenu {
+    props: SubMenuProps,
+    link: ComponentLink<Self>,
+    item_ref: NodeRef,
+    container_ref: NodeRef,
+    menu_link: WeakComponentLink<MatMenu>,
+    listeners: Vec<EventListener>,
+}
+
+/// Props for [`MatSubMenu`]
+#[derive(Properties, Clone)]
+pub struct SubMenuProps {
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Text of the item opening the child menu
+    pub label: Cow<'static, str>,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Binds to `action` event of the child menu
+    #[prop_or_default]
+    pub onaction: Callback<ListIndex>,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Open,
+    Close,
+    Action(ListIndex),
+}
+
+impl Component for MatSubMenu {
+    type Message = Msg;
+    type Properties = SubMenuProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            item_ref: NodeRef::default(),
+            container_ref: NodeRef::default(),
+            menu_link: WeakComponentLink::default(),
+            listeners: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Open => {
+                if !self.props.disabled {
+                    self.menu_link.show();
+                }
+            }
+            Msg::Close => self.menu_link.close(),
+            Msg::Action(index) => {
+                self.props.onaction.emit(index);
+                if let Some(item) = self.item_ref.cast::<Element>() {
+                    close_parent_menus(&item);
+                }
+            }
+        }
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        // the child menu isn't inside of the item so that its items aren't
+        // rendered in the item, and it is in a container stopping its events
+        html! {
+            <>
+            <mwc-list-item
+                class=self.props.classes.clone()
+                tabindex="-1"
+                hasMeta=""
+                disabled=self.props.disabled
+                aria-haspopup="menu"
+                aria-disabled=bool_to_option(self.props.disabled)
+                ref=self.item_ref.clone()
+            >
+                <span>{ self.props.label.clone() }</span>
+                <span slot="meta"><MatIcon>{ "chevron_right" }</MatIcon></span>
+            </mwc-list-item>
+            <div ref=self.container_ref.clone()>
+                <MatMenu
+                    fixed=true
+                    corner=Corner::TopEnd
+                    anchor=MenuAnchor::from(self.item_ref.clone())
+                    default_focus=DefaultFocusState::FirstItem
+                    menu_link=self.menu_link.clone()
+                    onaction=self.link.callback(Msg::Action)
+                >
+                    { self.props.children.clone() }
+                </MatMenu>
+            </div>
+            </>
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if !first_render {
+            return;
+        }
+        let item = self.item_ref.cast::<Element>().unwrap();
+        let container = self.container_ref.cast::<Element>().unwrap();
+
+        // the item mustn't be selected in the parent menu, which would close
+        // it
+        let link = self.link.clone();
+        let request_selected = EventListener::new(&item, "request-selected", move |event| {
+            event.stop_propagation();
+            link.send_message(Msg::Open);
+        });
+
+        let link = self.link.clone();
+        let options = EventListenerOptions::enable_prevent_default();
+        let item_keydown =
+            EventListener::new_with_options(&item, "keydown", options, move |event| {
+                let key = event.unchecked_ref::<KeyboardEvent>().key();
+                if matches!(key.as_str(), "ArrowRight" | "Enter" | " ") {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    link.send_message(Msg::Open);
+                }
+            });
+
+        let link = self.link.clone();
+        let container_keydown = EventListener::new(&container, "keydown", move |event| {
+            if event.unchecked_ref::<KeyboardEvent>().key() == "ArrowLeft" {
+                link.send_message(Msg::Close);
+            }
+        });
+
+        self.listeners = vec![request_selected, item_keydown, container_keydown];
+        self.listeners.extend(
+            CONTAINED_EVENTS.iter().map(|event| {
+                EventListener::new(&container, *event, |event| event.stop_propagation())
+            }),
+        );
+
+        // the child menu closes with its parent
+        if let Some(parent) = item.closest("mwc-menu").ok().flatten() {
+            let link = self.link.clone();
+            self.listeners
+                .push(EventListener::new(&parent, "closed", move |_| {
+                    link.send_message(Msg::Close)
+                }));
+        }
+    }
+}
+
+/// Closes every `mwc-menu` `element` is in
+fn close_parent_menus(element: &Element) {
+    let mut menu = element.closest("mwc-menu").ok().flatten();
+    while let Some(parent) = menu {
+        let _ = js_sys::Reflect::set(&parent, &JsValue::from("open"), &JsValue::FALSE);
+        menu = parent
+            .parent_element()
+            .and_then(|element| element.closest("mwc-menu").ok().flatten());
+    }
+}
@@ -0,0 +1,221 @@
+#[doc(inline)]
+pub use crate::list::ListOption;
+
+use crate::list::{ListIndex, SelectedDetail};
+use crate::menu::{Corner, DefaultFocusState, MenuAnchor};
+use crate::utils::update_complete;
+use crate::{MatButton, MatIconButton, MatListItem, MatMenu, WeakComponentLink};
+use std::borrow::Cow;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// A button opening a menu anchored to it
+///
+/// The button is a [`MatButton`], or a [`MatIconButton`] when `icon_button` is
+/// set. `aria-haspopup` and `aria-expanded` are set on the button so assistive
+/// technologies announce the menu.
+///
+/// The items of the menu can either be generated from the `items` prop, in
+/// which case `T` is the type of their values and the value of the activated
+/// item is emitted through `onaction`, or be passed as children.
+pub struct MatMenuButton<T: Clone + PartialEq + 'static = ()> {
+    props: MenuButtonProps<T>,
+    link: ComponentLink<Self>,
+    menu_link: WeakComponentLink<MatMenu>,
+    anchor_ref: NodeRef,
+    open: bool,
+}
+
+/// Props for [`MatMenuButton`]
+#[derive(Properties, Clone)]
+pub struct MenuButtonProps<T: Clone + PartialEq + 'static> {
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Label of the button, also used as the `aria-label` of an icon button
+    pub label: Cow<'static, str>,
+    /// Icon of the button. Required when `icon_button` is set.
+    #[prop_or_default]
+    pub icon: Option<Cow<'static, str>>,
+    /// Renders a [`MatIconButton`] showing `icon` instead of a [`MatButton`]
+    #[prop_or_default]
+    pub icon_button: bool,
+    #[prop_or_default]
+    pub raised: bool,
+    #[prop_or_default]
+    pub unelevated: bool,
+    #[prop_or_default]
+    pub outlined: bool,
+    #[prop_or_default]
+    pub dense: bool,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Options to render as the items of the menu.
+    ///
+    /// When this is not empty, `children` are ignored.
+    #[prop_or_default]
+    pub items: Vec<ListOption<T>>,
+    #[prop_or(Corner::BottomStart)]
+    pub corner: Corner,
+    #[prop_or_default]
+    pub fixed: bool,
+    #[prop_or_default]
+    pub quick: bool,
+    #[prop_or(DefaultFocusState::ListRoot)]
+    pub default_focus: DefaultFocusState,
+    #[prop_or_default]
+    pub multi: bool,
+    #[prop_or_default]
+    pub activatable: bool,
+    /// Emits the value of the activated item when `items` is used
+    #[prop_or_default]
+    pub onaction: Callback<T>,
+    /// Binds to `onselected` of the menu
+    #[prop_or_default]
+    pub onselected: Callback<SelectedDetail>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+pub enum Msg {
+    Toggle,
+    Opened,
+    Closed,
+    Action(ListIndex),
+}
+
+impl<T: Clone + PartialEq + 'static> Component for MatMenuButton<T> {
+    type Message = Msg;
+    type Properties = MenuButtonProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            menu_link: WeakComponentLink::default(),
+            anchor_ref: NodeRef::default(),
+            open: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => {
+                if self.open {
+                    self.menu_link.close();
+                } else if !self.props.disabled {
+                    self.menu_link.show();
+                }
+                false
+            }
+            Msg::Opened => {
+                self.open = true;
+                true
+            }
+            Msg::Closed => {
+                self.open = false;
+                true
+            }
+            Msg::Action(index) => {
+                if let ListIndex::Single(Some(index)) = index {
+                    if let Some(item) = self.props.items.get(index) {
+                        self.props.onaction.emit(item.value.clone());
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let button = if self.props.icon_button {
+            html! {
+                <MatIconButton
+                    label=self.props.label.clone()
+                    icon=self.props.icon.clone().unwrap_or_default()
+                    disabled=self.props.disabled
+                />
+            }
+        } else {
+            html! {
+                <MatButton
+                    label=self.props.label.to_string()
+                    icon=self.props.icon.clone()
+                    raised=self.props.raised
+                    unelevated=self.props.unelevated
+                    outlined=self.props.outlined
+                    dense=self.props.dense
+                    disabled=self.props.disabled
+                />
+            }
+        };
+
+        let items = if self.props.items.is_empty() {
+            self.props.children.iter().collect::<Html>()
+        } else {
+            self.props
+                .items
+                .iter()
+                .map(|item| {
+                    html! {
+                        <MatListItem disabled=item.disabled>{ item.label.clone() }</MatListItem>
+                    }
+                })
+                .collect::<Html>()
+        };
+
+        html! {
+            <div class=self.props.classes.clone() style="position: relative; display: inline-block;">
+                <span onclick=self.link.callback(|_| Msg::Toggle) ref=self.anchor_ref.clone()>
+                    { button }
+                </span>
+                <MatMenu
+                    anchor=MenuAnchor::from(self.anchor_ref.clone())
+                    corner=self.props.corner.clone()
+                    fixed=self.props.fixed
+                    quick=self.props.quick
+                    default_focus=self.props.default_focus.clone()
+                    multi=self.props.multi
+                    activatable=self.props.activatable
+                    menu_link=self.menu_link.clone()
+                    onopened=self.link.callback(|_| Msg::Opened)
+                    onclosed=self.link.callback(|_| Msg::Closed)
+                    onaction=self.link.callback(Msg::Action)
+                    onselected=self.props.onselected.clone()
+                >
+                    { items }
+                </MatMenu>
+            </div>
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if let Some(button) = self
+            .anchor_ref
+            .cast::<Element>()
+            .and_then(|anchor| anchor.first_element_child())
+        {
+            set_button_aria(button, self.open);
+        }
+    }
+}
+
+/// Sets the ARIA attributes of a menu button on the `button` rendered by the
+/// `mwc-button` or `mwc-icon-button` `element`
+fn set_button_aria(element: Element, expanded: bool) {
+    spawn_local(async move {
+        update_complete(&element).await;
+        let button = element
+            .shadow_root()
+            .and_then(|root| root.query_selector("button").ok().flatten());
+        if let Some(button) = button {
+            let _ = button.set_attribute("aria-haspopup", "menu");
+            let _ = button.set_attribute("aria-expanded", &expanded.to_string());
+        }
+    });
+}
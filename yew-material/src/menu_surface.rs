@@ -0,0 +1,207 @@
+use crate::menu::{Anchor, Corner, MenuAnchor, MenuCorner};
+use crate::{bool_to_option, to_option_string, WeakComponentLink};
+use gloo::events::EventListener;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use web_sys::Node;
+use yew::prelude::*;
+
+#[wasm_bindgen(module = "/build/mwc-menu-surface.js")]
+extern "C" {
+    #[derive(Debug)]
+    #[wasm_bindgen(extends = Node)]
+    type MenuSurface;
+
+    #[wasm_bindgen(getter, static_method_of = MenuSurface)]
+    fn _dummy_loader() -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn close(this: &MenuSurface);
+
+    #[wasm_bindgen(method, js_name = deregisterBodyClick)]
+    fn deregister_body_click(this: &MenuSurface);
+}
+
+loader_hack!(MenuSurface);
+
+/// The `mwc-menu-surface` Component
+///
+/// A popover with arbitrary content, positioned like [`MatMenu`][crate::MatMenu].
+///
+/// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#mwc-menu-surface)
+pub struct MatMenuSurface {
+    props: MenuSurfaceProps,
+    node_ref: NodeRef,
+    opened_listener: Option<EventListener>,
+    closed_listener: Option<EventListener>,
+    anchor: Anchor,
+    /// `stay_open_on_body_click` prop, read by the `opened` listener
+    stay_open_on_body_click: Rc<Cell<bool>>,
+}
+
+/// Props for `MatMenuSurface`
+///
+/// MWC Documentation [properties](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#propertiesattributes-1)
+/// and [events](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#events-1)
+#[derive(Properties, Clone)]
+pub struct MenuSurfaceProps {
+    #[prop_or_default]
+    pub classes: Classes,
+    /// The element the surface is positioned relative to.
+    ///
+    /// It is applied again whenever it changes, or whenever the element a
+    /// `NodeRef` anchor refers to changes.
+    #[prop_or_default]
+    pub anchor: Option<MenuAnchor>,
+    #[prop_or(Corner::TopStart)]
+    pub corner: Corner,
+    #[prop_or(MenuCorner::Start)]
+    pub menu_corner: MenuCorner,
+    #[prop_or_default]
+    pub quick: bool,
+    #[prop_or_default]
+    pub absolute: bool,
+    #[prop_or_default]
+    pub fixed: bool,
+    #[prop_or_default]
+    pub x: Option<isize>,
+    #[prop_or_default]
+    pub y: Option<isize>,
+    #[prop_or_default]
+    pub fullwidth: bool,
+    /// Keeps the surface open when the user clicks outside of it.
+    ///
+    /// `mwc-menu-surface` listens to clicks on the body once it has opened,
+    /// so changing this prop while the surface is open takes effect the next
+    /// time it opens.
+    #[prop_or_default]
+    pub stay_open_on_body_click: bool,
+    /// Binds to `opened` event on `mwc-menu-surface`
+    #[prop_or_default]
+    pub onopened: Callback<()>,
+    /// Binds to `closed` event on `mwc-menu-surface`
+    #[prop_or_default]
+    pub onclosed: Callback<()>,
+    /// `WeakComponentLink` for `MatMenuSurface` which provides the following
+    /// methods
+    /// - `show(&self)`
+    /// - `show_at(&self, anchor: impl Into<MenuAnchor>)`
+    /// - `close(&self)`
+    ///
+    /// See [`WeakComponentLink`](/yew_material/struct.WeakComponentLink.html)
+    /// documentation for more information
+    #[prop_or_default]
+    pub menu_surface_link: WeakComponentLink<MatMenuSurface>,
+    pub children: Children,
+}
+
+impl Component for MatMenuSurface {
+    type Message = ();
+    type Properties = MenuSurfaceProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        props.menu_surface_link.borrow_mut().replace(link);
+        MenuSurface::ensure_loaded();
+        let stay_open_on_body_click = Rc::new(Cell::new(props.stay_open_on_body_click));
+        Self {
+            props,
+            node_ref: NodeRef::default(),
+            opened_listener: None,
+            closed_listener: None,
+            anchor: Anchor::default(),
+            stay_open_on_body_click,
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        self.stay_open_on_body_click
+            .set(props.stay_open_on_body_click);
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <mwc-menu-surface
+                class=self.props.classes.clone()
+                corner=to_option_string(self.props.corner.to_string())
+                menuCorner=to_option_string(self.props.menu_corner.to_string())
+                quick=bool_to_option(self.props.quick)
+                absolute=bool_to_option(self.props.absolute)
+                fixed=bool_to_option(self.props.fixed)
+                x=self.props.x.map(|it| Cow::from(it.to_string()))
+                y=self.props.y.map(|it| Cow::from(it.to_string()))
+                fullwidth=bool_to_option(self.props.fullwidth)
+                ref=self.node_ref.clone()
+            >
+              { self.props.children.clone() }
+            </mwc-menu-surface>
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        self.anchor
+            .apply(&self.node_ref, self.props.anchor.as_ref());
+        let surface = self.node_ref.cast::<MenuSurface>().unwrap();
+
+        if self.opened_listener.is_none() {
+            let onopened = self.props.onopened.clone();
+            let stay_open_on_body_click = Rc::clone(&self.stay_open_on_body_click);
+            let element = self.node_ref.cast::<MenuSurface>().unwrap();
+            self.opened_listener = Some(EventListener::new(&surface, "opened", move |_| {
+                // the surface has just started listening to clicks on the
+                // body to close itself
+                if stay_open_on_body_click.get() {
+                    element.deregister_body_click();
+                }
+                onopened.emit(());
+            }));
+        }
+
+        if self.closed_listener.is_none() {
+            let onclosed = self.props.onclosed.clone();
+            self.closed_listener = Some(EventListener::new(&surface, "closed", move |_| {
+                onclosed.emit(());
+            }));
+        }
+    }
+}
+
+impl WeakComponentLink<MatMenuSurface> {
+    /// Binds to `show` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#methods-1) for details
+    pub fn show(&self) {
+        let link = self.borrow();
+        let surface = link.as_ref().unwrap().get_component().unwrap();
+        surface
+            .anchor
+            .show(&surface.node_ref, surface.props.anchor.as_ref());
+    }
+
+    /// Anchors the surface to `anchor` and shows it.
+    ///
+    /// The surface stays anchored to `anchor` until `anchor` prop changes.
+    pub fn show_at(&self, anchor: impl Into<MenuAnchor>) {
+        let link = self.borrow();
+        let surface = link.as_ref().unwrap().get_component().unwrap();
+        Anchor::show_at(&surface.node_ref, anchor.into());
+    }
+
+    /// Binds to `close` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/menu#methods-1) for details
+    pub fn close(&self) {
+        (*self.borrow().as_ref().unwrap().get_component().unwrap())
+            .node_ref
+            .cast::<MenuSurface>()
+            .unwrap()
+            .close();
+    }
+}
@@ -0,0 +1,279 @@
+use crate::list::{ListIndex, ListOption, MatCheckListItem, SelectedDetail};
+use crate::menu::{Corner, MenuAnchor};
+use crate::{bool_to_option, MatIcon, MatIconButton, MatMenu, WeakComponentLink};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use web_sys::{Element, EventTarget};
+use yew::prelude::*;
+
+// the field reads the custom properties `mwc-textfield` is themed with so
+// that it looks like the other form controls
+const FIELD_STYLE: &str = "display: flex; align-items: center; min-height: 56px; \
+    padding: 4px 8px 4px 16px; box-sizing: border-box; cursor: pointer; \
+    border-radius: var(--mdc-shape-small, 4px) var(--mdc-shape-small, 4px) 0 0; \
+    border-bottom: 1px solid var(--mdc-text-field-idle-line-color, rgba(0, 0, 0, 0.42)); \
+    background-color: var(--mdc-text-field-fill-color, whitesmoke);";
+const OUTLINED_FIELD_STYLE: &str = "display: flex; align-items: center; min-height: 56px; \
+    padding: 4px 8px 4px 16px; box-sizing: border-box; cursor: pointer; \
+    border-radius: var(--mdc-shape-small, 4px); \
+    border: 1px solid var(--mdc-text-field-outlined-idle-border-color, rgba(0, 0, 0, 0.38));";
+const LABEL_STYLE: &str = "flex: none; margin-right: 8px; \
+    color: var(--mdc-text-field-label-ink-color, rgba(0, 0, 0, 0.6));";
+const DISABLED_LABEL_STYLE: &str = "flex: none; margin-right: 8px; \
+    color: var(--mdc-text-field-disabled-ink-color, rgba(0, 0, 0, 0.38));";
+const CHIPS_STYLE: &str = "display: flex; flex-wrap: wrap; flex: 1;";
+const CHIP_STYLE: &str = "display: inline-flex; align-items: center; height: 32px; \
+    margin: 4px; padding: 0 12px; box-sizing: border-box; border-radius: 16px; \
+    border: 1px solid var(--mdc-theme-primary, #6200ee); \
+    color: var(--mdc-theme-primary, #6200ee); \
+    --mdc-icon-button-size: 32px; --mdc-icon-size: 18px;";
+/// The remove button takes the place of the padding at the end of the chip
+const REMOVABLE_CHIP_STYLE: &str = "padding-right: 0;";
+
+/// A multiple choice select
+///
+/// The options are rendered as [`MatCheckListItem`]s inside of a `multi`
+/// [`MatMenu`] which opens when the field is clicked. Chosen options are shown
+/// in the field as chips which can be removed while the select is enabled.
+///
+/// The field is themed with the `--mdc-text-field-*` custom properties of
+/// `mwc-textfield` and the chips with `--mdc-theme-primary`.
+pub struct MatMultiSelect<T: Clone + PartialEq + 'static> {
+    props: MultiSelectProps<T>,
+    link: ComponentLink<Self>,
+    menu_link: WeakComponentLink<MatMenu>,
+    field_ref: NodeRef,
+    selected: HashSet<usize>,
+    open: bool,
+}
+
+/// Props for [`MatMultiSelect`]
+#[derive(Properties, Clone)]
+pub struct MultiSelectProps<T: Clone + PartialEq + 'static> {
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub label: Cow<'static, str>,
+    #[prop_or_default]
+    pub outlined: bool,
+    #[prop_or_default]
+    pub disabled: bool,
+    pub options: Vec<ListOption<T>>,
+    /// Values of the selected options.
+    ///
+    /// The selection is updated whenever this prop changes.
+    #[prop_or_default]
+    pub selected: Vec<T>,
+    /// Emits the values of all the selected options, in the order of `options`,
+    /// whenever the selection changes
+    #[prop_or_default]
+    pub onchange: Callback<Vec<T>>,
+}
+
+pub enum Msg {
+    Open,
+    Opened,
+    Closed,
+    Selected(SelectedDetail),
+    Remove(usize),
+}
+
+impl<T: Clone + PartialEq + 'static> Component for MatMultiSelect<T> {
+    type Message = Msg;
+    type Properties = MultiSelectProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let selected = selected_indices(&props.options, &props.selected);
+        Self {
+            props,
+            link,
+            menu_link: WeakComponentLink::default(),
+            field_ref: NodeRef::default(),
+            selected,
+            open: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Open => {
+                if !self.props.disabled {
+                    self.menu_link.show();
+                }
+                false
+            }
+            Msg::Opened => {
+                self.open = true;
+                true
+            }
+            Msg::Closed => {
+                self.open = false;
+                true
+            }
+            Msg::Selected(detail) => {
+                let mut selected = self.selected.clone();
+                match (detail.diff, detail.index) {
+                    (Some(diff), _) => {
+                        for index in diff.removed {
+                            selected.remove(&index);
+                        }
+                        selected.extend(diff.added);
+                    }
+                    (None, ListIndex::Multi(indices)) => selected = indices,
+                    (None, ListIndex::Single(_)) => {}
+                }
+                self.set_selected(selected)
+            }
+            Msg::Remove(index) => {
+                if self.props.disabled {
+                    return false;
+                }
+                let mut selected = self.selected.clone();
+                selected.remove(&index);
+                self.set_selected(selected)
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        if self.props.selected != props.selected || self.props.options != props.options {
+            self.selected = selected_indices(&props.options, &props.selected);
+        }
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let style = if self.props.outlined {
+            OUTLINED_FIELD_STYLE
+        } else {
+            FIELD_STYLE
+        };
+        let label_style = if self.props.disabled {
+            DISABLED_LABEL_STYLE
+        } else {
+            LABEL_STYLE
+        };
+
+        let items = self
+            .props
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                html! {
+                    <MatCheckListItem
+                        key=index.to_string()
+                        left=true
+                        disabled=option.disabled
+                        selected=self.selected.contains(&index)
+                    >{ option.label.clone() }</MatCheckListItem>
+                }
+            })
+            .collect::<Html>();
+
+        let field_ref = self.field_ref.clone();
+        html! {
+            <div class=self.props.classes.clone() style="position: relative;">
+                <div
+                    style=style
+                    role="button"
+                    tabindex=if self.props.disabled { "-1" } else { "0" }
+                    aria-haspopup="listbox"
+                    aria-expanded=self.open.to_string()
+                    aria-disabled=bool_to_option(self.props.disabled)
+                    onclick=self.link.callback(|_| Msg::Open)
+                    onkeydown=self.link.batch_callback(move |e: KeyboardEvent| {
+                        // keys pressed on the remove buttons of the chips
+                        // bubble up to the field
+                        let field = field_ref.cast::<Element>().map(EventTarget::from);
+                        if e.target() != field {
+                            return None;
+                        }
+                        match e.key().as_str() {
+                            "Enter" | " " | "ArrowDown" => {
+                                e.prevent_default();
+                                Some(Msg::Open)
+                            }
+                            _ => None,
+                        }
+                    })
+                    ref=self.field_ref.clone()
+                >
+                    <span style=label_style>{ self.props.label.clone() }</span>
+                    <div style=CHIPS_STYLE>{ self.view_chips() }</div>
+                    <MatIcon>{ "arrow_drop_down" }</MatIcon>
+                </div>
+                <MatMenu
+                    multi=true
+                    corner=Corner::BottomStart
+                    anchor=MenuAnchor::from(self.field_ref.clone())
+                    menu_link=self.menu_link.clone()
+                    onopened=self.link.callback(|_| Msg::Opened)
+                    onclosed=self.link.callback(|_| Msg::Closed)
+                    onselected=self.link.callback(Msg::Selected)
+                >
+                    { items }
+                </MatMenu>
+            </div>
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> MatMultiSelect<T> {
+    fn view_chips(&self) -> Html {
+        let mut selected = self.selected.iter().copied().collect::<Vec<_>>();
+        selected.sort_unstable();
+        selected
+            .into_iter()
+            .filter_map(|index| Some((index, self.props.options.get(index)?)))
+            .map(|(index, option)| {
+                if self.props.disabled {
+                    return html! {
+                        <span style=CHIP_STYLE>{ option.label.clone() }</span>
+                    };
+                }
+                let remove = self.link.callback(move |e: MouseEvent| {
+                    // the click would open the menu otherwise
+                    e.stop_propagation();
+                    Msg::Remove(index)
+                });
+                html! {
+                    <span style=format!("{} {}", CHIP_STYLE, REMOVABLE_CHIP_STYLE)>
+                        { option.label.clone() }
+                        <span onclick=remove>
+                            <MatIconButton icon="cancel" label="Remove" />
+                        </span>
+                    </span>
+                }
+            })
+            .collect()
+    }
+
+    /// Updates the selection and emits `onchange` if it has changed
+    fn set_selected(&mut self, selected: HashSet<usize>) -> ShouldRender {
+        if selected == self.selected {
+            return false;
+        }
+        self.selected = selected;
+        let values = self
+            .props
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.selected.contains(index))
+            .map(|(_, option)| option.value.clone())
+            .collect();
+        self.props.onchange.emit(values);
+        true
+    }
+}
+
+fn selected_indices<T: PartialEq>(options: &[ListOption<T>], values: &[T]) -> HashSet<usize> {
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| values.contains(&option.value))
+        .map(|(index, _)| index)
+        .collect()
+}
@@ -1,15 +1,18 @@
 #[doc(inline)]
-pub use crate::list::{ActionDetail, ListIndex, SelectedDetail};
+pub use crate::list::{ActionDetail, ListIndex, ListOption, SelectedDetail};
 
+use crate::list::MatListItem;
 use crate::text_inputs::{
-    validity_state::ValidityStateJS, NativeValidityState, ValidityState, ValidityTransform,
+    validity_state::ValidityStateJS, MatTextField, NativeValidityState, ValidityState,
+    ValidityTransform,
 };
 use crate::utils::WeakComponentLink;
 use crate::{bool_to_option, event_into_details, to_option_string};
 use gloo::events::EventListener;
 use std::borrow::Cow;
 use wasm_bindgen::prelude::*;
-use web_sys::Node;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, Node};
 use yew::prelude::*;
 
 #[wasm_bindgen(module = "/build/mwc-select.js")]
@@ -21,8 +24,32 @@ extern "C" {
     #[wasm_bindgen(getter, static_method_of = Select)]
     fn _dummy_loader() -> JsValue;
 
+    #[wasm_bindgen(method, getter)]
+    fn index(this: &Select) -> isize;
+
+    #[wasm_bindgen(method, getter)]
+    fn value(this: &Select) -> String;
+
+    #[wasm_bindgen(method, getter)]
+    fn selected(this: &Select) -> Option<HtmlElement>;
+
+    #[wasm_bindgen(method, getter)]
+    fn items(this: &Select) -> js_sys::Array;
+
+    #[wasm_bindgen(method)]
+    fn select(this: &Select, index: isize);
+
     #[wasm_bindgen(method)]
-    fn select(this: &Select, index: usize);
+    fn layout(this: &Select, update_items: bool);
+
+    #[wasm_bindgen(method, js_name = checkValidity)]
+    fn check_validity(this: &Select) -> bool;
+
+    #[wasm_bindgen(method, js_name = reportValidity)]
+    fn report_validity(this: &Select) -> bool;
+
+    #[wasm_bindgen(method, js_name = setCustomValidity)]
+    fn set_custom_validity(this: &Select, message: &str);
 
     #[wasm_bindgen(method, setter = validityTransform)]
     fn set_validity_transform(
@@ -36,9 +63,23 @@ loader_hack!(Select);
 /// The `mwc-select` component
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/master/packages/select)
-pub struct MatSelect {
-    props: Props,
+///
+/// ## Options
+///
+/// Items can either be passed as `MatListItem` children or be generated from
+/// the `options` prop. In the latter case, `T` is the type of the options'
+/// values and selections are reported as `Option<T>` through `onchange`.
+pub struct MatSelect<T: Clone + PartialEq + 'static = ()> {
+    props: Props<T>,
+    link: ComponentLink<Self>,
     node_ref: NodeRef,
+    selected: Option<T>,
+    sync_selected: bool,
+    filter: String,
+    /// Indices of the options matching `filter`
+    visible_options: Vec<usize>,
+    /// Index of the selected option when it doesn't match `filter`
+    hidden_selected: Option<usize>,
     validity_transform_closure:
         Option<Closure<dyn Fn(String, NativeValidityState) -> ValidityStateJS>>,
     opened_listener: Option<EventListener>,
@@ -54,7 +95,7 @@ pub struct MatSelect {
 /// - [Properties](https://github.com/material-components/material-components-web-components/tree/master/packages/select#propertiesattributes)
 /// - [Events](https://github.com/material-components/material-components-web-components/tree/master/packages/select#events)
 #[derive(Properties, Clone)]
-pub struct Props {
+pub struct Props<T: Clone + PartialEq + 'static> {
     #[prop_or_default]
     pub classes: Classes,
     #[prop_or_default]
@@ -75,8 +116,6 @@ pub struct Props {
     pub required: bool,
     #[prop_or_default]
     pub validation_message: Cow<'static, str>,
-    #[prop_or_default]
-    pub items: Cow<'static, str>,
     #[prop_or(- 1)]
     pub index: i64,
     #[prop_or_default]
@@ -85,12 +124,50 @@ pub struct Props {
     pub validate_on_initial_render: bool,
     #[prop_or_default]
     pub children: Children,
-    /// [`WeakComponentLink`] for `MatList` which provides the following methods
-    /// - ```select(&self)```
+    /// Options to render as the items of the select.
+    ///
+    /// When this is not empty, `children` are ignored.
+    #[prop_or_default]
+    pub options: Vec<ListOption<T>>,
+    /// Renders a text field at the top of the menu which filters `options`
+    /// by their labels.
+    ///
+    /// The selected option is kept even when it is filtered out.
+    #[prop_or_default]
+    pub searchable: bool,
+    /// Label of the text field rendered when `searchable` is set
+    #[prop_or(Cow::from("Search"))]
+    pub search_label: Cow<'static, str>,
+    /// Value of the selected option when `options` is used.
+    ///
+    /// The element is updated whenever this prop changes. `None` clears the
+    /// selection.
+    #[prop_or_default]
+    pub selected: Option<T>,
+    /// Emits the value of the newly selected option when `options` is used.
+    ///
+    /// Selections caused by the `selected` prop are not emitted.
+    #[prop_or_default]
+    pub onchange: Callback<Option<T>>,
+    /// [`WeakComponentLink`] for `MatSelect` which provides the following methods.
+    ///
+    /// When `options` is used, `select` and `index` take and return indices
+    /// of `options`, while `items` returns the rendered items, which are the
+    /// options matching the search followed by the selected option if it
+    /// doesn't match.
+    /// - ```select(&self, index: usize)```
+    /// - ```layout(&self, update_items: bool)```
+    /// - ```check_validity(&self) -> bool```
+    /// - ```report_validity(&self) -> bool```
+    /// - ```set_custom_validity(&self, message: &str)```
+    /// - ```selected(&self) -> Option<HtmlElement>```
+    /// - ```items(&self) -> Vec<HtmlElement>```
+    /// - ```value(&self) -> String```
+    /// - ```index(&self) -> Option<usize>```
     ///
     /// See [`WeakComponentLink`] documentation for more information
     #[prop_or_default]
-    pub select_link: WeakComponentLink<MatSelect>,
+    pub select_link: WeakComponentLink<MatSelect<T>>,
     /// Binds to `opened` event on `mwc-select-surface`
     ///
     /// See events docs to learn more.
@@ -113,30 +190,103 @@ pub struct Props {
     pub onselected: Callback<SelectedDetail>,
 }
 
-impl Component for MatSelect {
-    type Message = ();
-    type Properties = Props;
+pub enum Msg {
+    Selected(SelectedDetail),
+    /// Selects the option at this index of `options`
+    Select(usize),
+    Closed,
+    Filter(String),
+}
+
+impl<T: Clone + PartialEq + 'static> Component for MatSelect<T> {
+    type Message = Msg;
+    type Properties = Props<T>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        props.select_link.borrow_mut().replace(link);
+        props.select_link.borrow_mut().replace(link.clone());
         Select::ensure_loaded();
-        Self {
-            props,
+        let mut select = Self {
+            link,
             node_ref: NodeRef::default(),
+            selected: props.selected.clone(),
+            sync_selected: false,
+            filter: String::new(),
+            visible_options: Vec::new(),
+            hidden_selected: None,
+            props,
             validity_transform_closure: None,
             opened_listener: None,
             closed_listener: None,
             action_listener: None,
             selected_listener: None,
-        }
+        };
+        select.filter_options();
+        select
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Selected(detail) => {
+                let mut should_render = false;
+                if !self.props.options.is_empty() {
+                    let value = match detail.index {
+                        ListIndex::Single(Some(index)) => self
+                            .item_options()
+                            .nth(index)
+                            .map(|option| self.props.options[option].value.clone()),
+                        _ => None,
+                    };
+                    if value != self.selected {
+                        self.selected = value.clone();
+                        self.props.onchange.emit(value);
+                        should_render = !self.filter.is_empty();
+                        if should_render {
+                            self.filter_options();
+                        }
+                    }
+                }
+                self.props.onselected.emit(detail);
+                should_render
+            }
+            Msg::Select(index) => {
+                let value = self
+                    .props
+                    .options
+                    .get(index)
+                    .map(|option| option.value.clone());
+                if value != self.selected {
+                    self.selected = value.clone();
+                    self.props.onchange.emit(value);
+                }
+                self.sync_selected = true;
+                self.filter_options();
+                true
+            }
+            Msg::Closed => {
+                self.props.onclosed.emit(());
+                if self.filter.is_empty() {
+                    false
+                } else {
+                    self.filter.clear();
+                    self.filter_options();
+                    true
+                }
+            }
+            Msg::Filter(filter) => {
+                self.filter = filter;
+                self.filter_options();
+                true
+            }
+        }
     }
 
     fn change(&mut self, props: Self::Properties) -> bool {
+        if self.props.selected != props.selected {
+            self.selected = props.selected.clone();
+            self.sync_selected = true;
+        }
         self.props = props;
+        self.filter_options();
         true
     }
 
@@ -153,12 +303,11 @@ impl Component for MatSelect {
                 helper=self.props.helper.clone()
                 required=self.props.required
                 validationMessage=self.props.validation_message.clone()
-                items=self.props.items.clone()
                 index=to_option_string(self.props.index)
                 validateOnInitialRender=bool_to_option(self.props.validate_on_initial_render)
                 ref=self.node_ref.clone()
             >
-              { self.props.children.clone() }
+              { self.view_items() }
             </mwc-select>
         }
     }
@@ -186,9 +335,9 @@ impl Component for MatSelect {
         }
 
         if self.closed_listener.is_none() {
-            let onclosed = self.props.onclosed.clone();
+            let link = self.link.clone();
             self.closed_listener = Some(EventListener::new(&element, "closed", move |_| {
-                onclosed.emit(())
+                link.send_message(Msg::Closed)
             }));
         }
 
@@ -200,21 +349,204 @@ impl Component for MatSelect {
         }
 
         if self.selected_listener.is_none() {
-            let on_selected = self.props.onselected.clone();
+            let link = self.link.clone();
             self.selected_listener = Some(EventListener::new(&element, "selected", move |event| {
-                on_selected.emit(SelectedDetail::from(event_into_details(event)))
+                link.send_message(Msg::Selected(SelectedDetail::from(event_into_details(
+                    event,
+                ))))
             }));
         }
+
+        if self.sync_selected {
+            self.sync_selected = false;
+            let index = self.selected_index();
+            if element.index() != index {
+                element.select(index);
+            }
+        }
     }
 }
 
-impl WeakComponentLink<MatSelect> {
-    pub fn select(&self, val: usize) {
-        let c = (*self.borrow().as_ref().unwrap().get_component().unwrap())
+impl<T: Clone + PartialEq + 'static> MatSelect<T> {
+    fn view_items(&self) -> Html {
+        if self.props.options.is_empty() {
+            return self.props.children.iter().collect();
+        }
+
+        let search = if self.props.searchable {
+            html! {
+                // keep the list and the select's typeahead from handling keys
+                // meant for the text field
+                <div onkeydown=Callback::from(|e: KeyboardEvent| e.stop_propagation())>
+                    <MatTextField
+                        label=self.props.search_label.clone()
+                        icon="search"
+                        value=self.filter.clone()
+                        oninput=self.link.callback(|data: InputData| Msg::Filter(data.value))
+                    />
+                </div>
+            }
+        } else {
+            html! {}
+        };
+
+        let items = self
+            .item_options()
+            .map(|index| {
+                let option = &self.props.options[index];
+                // the selected option stays in the list when it is filtered
+                // out, hidden, so that the select does not lose its selection
+                html! {
+                    <MatListItem
+                        key=index.to_string()
+                        value=index.to_string()
+                        disabled=option.disabled
+                        selected=self.selected.as_ref() == Some(&option.value)
+                        hidden=self.hidden_selected == Some(index)
+                    >{ option.label.clone() }</MatListItem>
+                }
+            })
+            .collect::<Html>();
+
+        html! {
+            <>
+                { search }
+                { items }
+            </>
+        }
+    }
+
+    /// Updates the options rendered as items after `filter`, `selected` or
+    /// `options` changed
+    fn filter_options(&mut self) {
+        let filter = self.filter.to_lowercase();
+        self.visible_options.clear();
+        self.hidden_selected = None;
+        for (index, option) in self.props.options.iter().enumerate() {
+            if filter.is_empty() || option.label.to_lowercase().contains(&filter) {
+                self.visible_options.push(index);
+            } else if self.selected.as_ref() == Some(&option.value) {
+                self.hidden_selected = Some(index);
+            }
+        }
+    }
+
+    /// Indices of the options rendered as items, in order.
+    ///
+    /// These are the options matching the filter followed by the selected
+    /// option if it has been filtered out.
+    fn item_options(&self) -> impl Iterator<Item = usize> + '_ {
+        self.visible_options
+            .iter()
+            .copied()
+            .chain(self.hidden_selected)
+    }
+
+    /// Item index of the selected option or `-1` if there is none
+    fn selected_index(&self) -> isize {
+        self.selected
+            .as_ref()
+            .and_then(|selected| {
+                self.item_options()
+                    .position(|index| &self.props.options[index].value == selected)
+            })
+            .map_or(-1, |index| index as isize)
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> WeakComponentLink<MatSelect<T>> {
+    fn select_element(&self) -> Select {
+        self.borrow()
+            .as_ref()
+            .unwrap()
+            .get_component()
+            .unwrap()
             .node_ref
-            .clone();
-        let select_element = c.cast::<Select>().unwrap();
-        select_element.select(val);
+            .cast::<Select>()
+            .unwrap()
+    }
+
+    /// Binds to `select` method.
+    ///
+    /// When `options` is used, `val` is the index of the option in `options`.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/select#methods) for details
+    pub fn select(&self, val: usize) {
+        let link = self.borrow().as_ref().unwrap().clone();
+        let options_mode = !link.get_component().unwrap().props.options.is_empty();
+        if options_mode {
+            link.send_message(Msg::Select(val));
+        } else {
+            self.select_element().select(val as isize);
+        }
+    }
+
+    /// Binds to `layout` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/select#methods) for details
+    pub fn layout(&self, update_items: bool) {
+        self.select_element().layout(update_items)
+    }
+
+    /// Binds to `checkValidity` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/select#methods) for details
+    pub fn check_validity(&self) -> bool {
+        self.select_element().check_validity()
+    }
+
+    /// Binds to `reportValidity` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/select#methods) for details
+    pub fn report_validity(&self) -> bool {
+        self.select_element().report_validity()
+    }
+
+    /// Binds to `setCustomValidity` method.
+    ///
+    /// See [here](https://github.com/material-components/material-components-web-components/tree/master/packages/select#methods) for details
+    pub fn set_custom_validity(&self, message: &str) {
+        self.select_element().set_custom_validity(message)
+    }
+
+    /// Getter for `selected`: the selected item, if any
+    pub fn selected(&self) -> Option<HtmlElement> {
+        self.select_element().selected()
+    }
+
+    /// Getter for `items`: the `mwc-list-item`s of the select
+    ///
+    /// When `options` is used, these are only the rendered options, see
+    /// `select_link`.
+    pub fn items(&self) -> Vec<HtmlElement> {
+        self.select_element()
+            .items()
+            .iter()
+            .map(JsCast::unchecked_into)
+            .collect()
+    }
+
+    /// Getter for `value`: the value of the selected item
+    pub fn value(&self) -> String {
+        self.select_element().value()
+    }
+
+    /// Getter for `index`: the index of the selected item, if any
+    ///
+    /// When `options` is used, this is the index of the selected option in
+    /// `options`.
+    pub fn index(&self) -> Option<usize> {
+        let index = self.select_element().index();
+        if index < 0 {
+            return None;
+        }
+        let link = self.borrow().as_ref().unwrap().clone();
+        let select = link.get_component().unwrap();
+        if select.props.options.is_empty() {
+            Some(index as usize)
+        } else {
+            select.item_options().nth(index as usize)
+        }
     }
 }
 
@@ -1,10 +1,21 @@
-use crate::{bool_to_option, event_into_details, to_option_string, WeakComponentLink};
+mod close_reason;
+mod pending_operation;
+
+pub use close_reason::SnackbarCloseReason;
+pub use pending_operation::PendingOperation;
+
+use crate::{
+    bool_to_option, event_into_details, to_option_string, MatButton, MatIconButton,
+    WeakComponentLink,
+};
 use gloo::events::EventListener;
 use js_sys::Object;
 use std::borrow::Cow;
+use std::cell::Cell;
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::Node;
+use web_sys::{Element, Node};
 use yew::prelude::*;
 
 #[wasm_bindgen(module = "/build/mwc-snackbar.js")]
@@ -41,6 +52,12 @@ loader_hack!(Snackbar);
 /// The `mwc-snackbar` component
 ///
 /// [MWC Documentation](https://github.com/material-components/material-components-web-components/tree/master/packages/snackbar)
+///
+/// ## Actions
+///
+/// `action_label` and `dismissible` render the action and dismiss buttons.
+/// Other elements can be passed as children with `slot="action"` or
+/// `slot="dismiss"`.
 pub struct MatSnackbar {
     props: SnackbarProps,
     node_ref: NodeRef,
@@ -48,6 +65,10 @@ pub struct MatSnackbar {
     opened_listener: Option<EventListener>,
     closing_listener: Option<EventListener>,
     closed_listener: Option<EventListener>,
+    /// Whether the user dismissed the snackbar since it last closed.
+    /// `mwc-snackbar` closes with the `dismiss` reason on timeouts too.
+    dismissed: Rc<Cell<bool>>,
+    dismiss_listeners: Vec<EventListener>,
 }
 
 /// Props for [`MatSnackbar`]
@@ -72,6 +93,15 @@ pub struct SnackbarProps {
     pub stacked: bool,
     #[prop_or_default]
     pub leading: bool,
+    /// Label of the action button. It isn't rendered when it isn't set.
+    #[prop_or_default]
+    pub action_label: Option<Cow<'static, str>>,
+    /// Renders a dismiss button
+    #[prop_or_default]
+    pub dismissible: bool,
+    /// `aria-label` of the dismiss button
+    #[prop_or(Cow::from("Dismiss"))]
+    pub dismiss_label: Cow<'static, str>,
     /// Binds to `MDCSnackbar:opening` event
     ///
     /// See events docs to learn more.
@@ -82,25 +112,28 @@ pub struct SnackbarProps {
     /// See events docs to learn more.
     #[prop_or_default]
     pub onopened: Callback<()>,
-    /// Binds to `MDCSnackbar:` event
+    /// Binds to `MDCSnackbar:closing` event
     ///
     /// The argument passed to callback corresponds to `reason` parameter of the
-    /// event
+    /// event. It is `None` when the snackbar is closed by setting `open` to
+    /// `false`.
     ///
     /// See events docs to learn more.
     #[prop_or_default]
-    pub onclosing: Callback<Option<String>>,
-    /// Binds to `closing` event
+    pub onclosing: Callback<Option<SnackbarCloseReason>>,
+    /// Binds to `MDCSnackbar:closed` event
     ///
     /// The argument passed to callback corresponds to `reason` parameter of the
-    /// event
+    /// event. It is `None` when the snackbar is closed by setting `open` to
+    /// `false`.
     ///
     /// See events docs to learn more.
     #[prop_or_default]
-    pub onclosed: Callback<Option<String>>,
-    /// [`WeakComponentLink`] for `MatList` which provides the following methods
+    pub onclosed: Callback<Option<SnackbarCloseReason>>,
+    /// [`WeakComponentLink`] for `MatSnackbar` which provides the following
+    /// methods
     /// - ```show(&self)```
-    /// - ```close(&self, reason: &str)```
+    /// - ```close(&self, reason: SnackbarCloseReason)```
     ///
     /// See [`WeakComponentLink`] documentation for more information
     #[prop_or_default]
@@ -123,6 +156,8 @@ impl Component for MatSnackbar {
             opened_listener: None,
             closing_listener: None,
             closed_listener: None,
+            dismissed: Rc::default(),
+            dismiss_listeners: Vec::new(),
         }
     }
 
@@ -131,11 +166,38 @@ impl Component for MatSnackbar {
     }
 
     fn change(&mut self, props: Self::Properties) -> bool {
+        // the listeners are added again with the new callbacks when rendered
+        if props.onopening != self.props.onopening {
+            self.opening_listener = None;
+        }
+        if props.onopened != self.props.onopened {
+            self.opened_listener = None;
+        }
+        if props.onclosing != self.props.onclosing {
+            self.closing_listener = None;
+        }
+        if props.onclosed != self.props.onclosed {
+            self.closed_listener = None;
+        }
         self.props = props;
         true
     }
 
     fn view(&self) -> Html {
+        let action = self.props.action_label.as_ref().map(|label| {
+            html! {
+                <span slot="action">
+                    <MatButton label=label.to_string() />
+                </span>
+            }
+        });
+        let dismiss = self.props.dismissible.then(|| {
+            html! {
+                <span slot="dismiss">
+                    <MatIconButton icon="close" label=self.props.dismiss_label.clone() />
+                </span>
+            }
+        });
         html! {
             <mwc-snackbar
                 class=self.props.classes.clone()
@@ -145,14 +207,22 @@ impl Component for MatSnackbar {
                 stacked=bool_to_option(self.props.stacked)
                 leading=bool_to_option(self.props.leading)
                 ref=self.node_ref.clone()
-            >{ self.props.children.clone() }</mwc-snackbar>
+            >
+                { action.unwrap_or_default() }
+                { dismiss.unwrap_or_default() }
+                { self.props.children.clone() }
+            </mwc-snackbar>
         }
     }
 
-    fn rendered(&mut self, _first_render: bool) {
+    fn rendered(&mut self, first_render: bool) {
         let element = self.node_ref.cast::<Snackbar>().unwrap();
         element.set_open(self.props.open);
 
+        if first_render {
+            self.listen_dismiss(&element);
+        }
+
         if self.opening_listener.is_none() {
             let on_opening = self.props.onopening.clone();
             self.opening_listener = Some(EventListener::new(
@@ -177,28 +247,62 @@ impl Component for MatSnackbar {
 
         if self.closing_listener.is_none() {
             let on_closing = self.props.onclosing.clone();
+            let dismissed = Rc::clone(&self.dismissed);
             self.closing_listener = Some(EventListener::new(
                 &element,
                 "MDCSnackbar:closing",
                 move |event| {
-                    on_closing.emit(event_into_details_reason(event));
+                    on_closing.emit(event_into_details_reason(event, dismissed.get()));
                 },
             ));
         }
 
         if self.closed_listener.is_none() {
             let on_closed = self.props.onclosed.clone();
+            let dismissed = Rc::clone(&self.dismissed);
             self.closed_listener = Some(EventListener::new(
                 &element,
                 "MDCSnackbar:closed",
                 move |event| {
-                    on_closed.emit(event_into_details_reason(event));
+                    on_closed.emit(event_into_details_reason(event, dismissed.replace(false)));
                 },
             ));
         }
     }
 }
 
+impl MatSnackbar {
+    /// Records the user dismissing the snackbar, either by clicking an element
+    /// in the `dismiss` slot or by pressing `Escape`
+    fn listen_dismiss(&mut self, element: &Snackbar) {
+        let dismissed = Rc::clone(&self.dismissed);
+        let click = EventListener::new(element, "click", move |event| {
+            let in_dismiss_slot = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|target| target.closest("[slot=\"dismiss\"]").ok().flatten())
+                .is_some();
+            if in_dismiss_slot {
+                dismissed.set(true);
+            }
+        });
+
+        let dismissed = Rc::clone(&self.dismissed);
+        let snackbar = JsValue::from(element);
+        let keydown = EventListener::new(element, "keydown", move |event| {
+            let close_on_escape = js_sys::Reflect::get(&snackbar, &JsValue::from("closeOnEscape"))
+                .ok()
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false);
+            if close_on_escape && event.unchecked_ref::<KeyboardEvent>().key() == "Escape" {
+                dismissed.set(true);
+            }
+        });
+
+        self.dismiss_listeners = vec![click, keydown];
+    }
+}
+
 impl WeakComponentLink<MatSnackbar> {
     pub fn show(&self) {
         (*self.borrow().as_ref().unwrap().get_component().unwrap())
@@ -208,20 +312,29 @@ impl WeakComponentLink<MatSnackbar> {
             .show()
     }
 
-    pub fn close(&self, reason: &str) {
-        (*self.borrow().as_ref().unwrap().get_component().unwrap())
+    pub fn close(&self, reason: SnackbarCloseReason) {
+        let link = self.borrow();
+        let snackbar = link.as_ref().unwrap().get_component().unwrap();
+        snackbar
+            .dismissed
+            .set(reason == SnackbarCloseReason::Dismiss);
+        snackbar
             .node_ref
             .cast::<Snackbar>()
             .unwrap()
-            .close(&JsValue::from_str(reason))
+            .close(&JsValue::from_str(reason.as_mwc_reason()))
     }
 }
 
-fn event_into_details_reason(event: &Event) -> Option<String> {
+fn event_into_details_reason(event: &Event, dismissed: bool) -> Option<SnackbarCloseReason> {
     let details: JsValue = event_into_details(event);
     if details.is_undefined() {
+        return None;
+    }
+    let reason = details.unchecked_into::<DetailsReason>().reason();
+    if reason.is_empty() {
         None
     } else {
-        Some(details.unchecked_into::<DetailsReason>().reason())
+        Some(SnackbarCloseReason::from_mwc_reason(reason, dismissed))
     }
 }
@@ -0,0 +1,35 @@
+/// Why a [`MatSnackbar`][crate::MatSnackbar] closed
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnackbarCloseReason {
+    /// The action button was clicked
+    Action,
+    /// The dismiss button was clicked or `Escape` was pressed
+    Dismiss,
+    /// The snackbar was shown for `timeout_ms`
+    Timeout,
+    /// The snackbar was closed with this reason with the `close` method
+    Custom(String),
+}
+
+impl SnackbarCloseReason {
+    /// The `reason` `mwc-snackbar` uses for this reason. It uses `dismiss` for
+    /// timeouts too.
+    pub(crate) fn as_mwc_reason(&self) -> &str {
+        match self {
+            SnackbarCloseReason::Action => "action",
+            SnackbarCloseReason::Dismiss | SnackbarCloseReason::Timeout => "dismiss",
+            SnackbarCloseReason::Custom(reason) => reason,
+        }
+    }
+
+    /// Reads the `reason` of a closing or closed event. `dismissed` tells
+    /// whether the user dismissed the snackbar.
+    pub(crate) fn from_mwc_reason(reason: String, dismissed: bool) -> Self {
+        match reason.as_str() {
+            "action" => SnackbarCloseReason::Action,
+            "dismiss" if dismissed => SnackbarCloseReason::Dismiss,
+            "dismiss" => SnackbarCloseReason::Timeout,
+            _ => SnackbarCloseReason::Custom(reason),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::SnackbarCloseReason;
+use std::cell::Cell;
+use std::rc::Rc;
+use yew::Callback;
+
+/// An operation which can be undone from a snackbar until it closes
+///
+/// It is rolled back when the snackbar closes because its action was clicked,
+/// and committed when it closes for any other reason, including when another
+/// notification replaces it. It is settled only once, by the first close.
+///
+/// ```no_run
+/// # use yew::{html, Callback};
+/// # use std::borrow::Cow;
+/// # use yew_material::MatSnackbar;
+/// use yew_material::snackbar::PendingOperation;
+///
+/// let delete = PendingOperation::new(
+///     Callback::from(|_| { /* delete the item */ }),
+///     Callback::from(|_| { /* show the item again */ }),
+/// );
+/// html! {
+///     <MatSnackbar
+///         label_text="Item deleted"
+///         action_label=Cow::from("Undo")
+///         onclosed=delete.onclosed()
+///     />
+/// };
+/// ```
+#[derive(Clone)]
+pub struct PendingOperation {
+    commit: Callback<()>,
+    rollback: Callback<()>,
+    settled: Rc<Cell<bool>>,
+}
+
+impl PendingOperation {
+    pub fn new(commit: Callback<()>, rollback: Callback<()>) -> Self {
+        Self {
+            commit,
+            rollback,
+            settled: Rc::default(),
+        }
+    }
+
+    /// Commits or rolls back the operation for a snackbar which closed with
+    /// `reason`
+    pub fn settle(&self, reason: Option<&SnackbarCloseReason>) {
+        if self.settled.replace(true) {
+            return;
+        }
+        match reason {
+            Some(SnackbarCloseReason::Action) => self.rollback.emit(()),
+            _ => self.commit.emit(()),
+        }
+    }
+
+    /// Whether the operation has been committed or rolled back
+    pub fn is_settled(&self) -> bool {
+        self.settled.get()
+    }
+
+    /// A callback for `onclosed` of [`MatSnackbar`][crate::MatSnackbar]
+    /// settling the operation
+    pub fn onclosed(&self) -> Callback<Option<SnackbarCloseReason>> {
+        let operation = self.clone();
+        Callback::from(move |reason: Option<SnackbarCloseReason>| operation.settle(reason.as_ref()))
+    }
+}
@@ -0,0 +1,377 @@
+//! An app-wide queue of notifications shown in a single snackbar
+//!
+//! [`MatSnackbarHost`] must be rendered once, usually at the root of the app.
+//! It shows the queued notifications one at a time in a [`MatSnackbar`],
+//! which can then be sent from anywhere:
+//!
+//! ```no_run
+//! # use yew::Callback;
+//! use yew_material::snackbar_queue::{notify, notify_with_action};
+//!
+//! notify("Message sent");
+//! notify_with_action("Message archived", "Undo", Callback::from(|_| {
+//!     // unarchive the message
+//! }));
+//! ```
+//!
+//! Notifications are shown by decreasing [`Priority`], and in the order they
+//! were sent when they have the same priority. A notification with a higher
+//! priority than the one being shown replaces it right away.
+
+use crate::snackbar::{PendingOperation, SnackbarCloseReason};
+use crate::{MatSnackbar, WeakComponentLink};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use yew::agent::{Agent, AgentLink, Bridge, Bridged, Context, Dispatched, HandlerId};
+use yew::prelude::*;
+
+/// `reason` a notification is closed with when one with a higher priority
+/// replaces it, as in `SnackbarCloseReason::Custom(PREEMPTED_REASON)`
+pub const PREEMPTED_REASON: &str = "preempted";
+
+/// Shows `message` once the notifications sent before it have been shown.
+pub fn notify(message: impl Into<Cow<'static, str>>) {
+    enqueue(Notification::new(message))
+}
+
+/// Shows `message` with a button labelled `action_label`, emitting `onaction`
+/// when it is clicked.
+pub fn notify_with_action(
+    message: impl Into<Cow<'static, str>>,
+    action_label: impl Into<Cow<'static, str>>,
+    onaction: Callback<()>,
+) {
+    enqueue(Notification::new(message).action(action_label, onaction))
+}
+
+/// Shows `message` with a button labelled `action_label` undoing `operation`.
+///
+/// `operation` is rolled back if the button is clicked, and committed when the
+/// notification closes for any other reason, including being replaced by a
+/// notification with a higher priority.
+pub fn notify_with_undo(
+    message: impl Into<Cow<'static, str>>,
+    action_label: impl Into<Cow<'static, str>>,
+    operation: PendingOperation,
+) {
+    enqueue(
+        Notification::new(message)
+            .action(action_label, Callback::noop())
+            .onclosed(operation.onclosed()),
+    )
+}
+
+/// Queues `notification`
+pub fn enqueue(notification: Notification) {
+    SnackbarQueue::dispatcher().send(Request::Enqueue(notification))
+}
+
+/// Priority of a [`Notification`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// A message shown by [`MatSnackbarHost`]
+#[derive(Clone)]
+pub struct Notification {
+    pub message: Cow<'static, str>,
+    /// Label of the action button and the callback emitted when it's clicked
+    pub action: Option<(Cow<'static, str>, Callback<()>)>,
+    pub priority: Priority,
+    /// `timeout_ms` of the snackbar while it shows this notification
+    pub timeout_ms: i32,
+    /// Emitted with the reason the notification closed with. The reason is
+    /// `SnackbarCloseReason::Custom(PREEMPTED_REASON)` when a notification with
+    /// a higher priority replaced it, and `None` when [`MatSnackbarHost`] was
+    /// destroyed while it was shown.
+    pub onclosed: Callback<Option<SnackbarCloseReason>>,
+}
+
+impl Notification {
+    /// A notification with [`Priority::Normal`], shown for 5 seconds
+    pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            message: message.into(),
+            action: None,
+            priority: Priority::default(),
+            timeout_ms: 5000,
+            onclosed: Callback::noop(),
+        }
+    }
+
+    pub fn action(mut self, label: impl Into<Cow<'static, str>>, onaction: Callback<()>) -> Self {
+        self.action = Some((label.into(), onaction));
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn timeout_ms(mut self, timeout_ms: i32) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    pub fn onclosed(mut self, onclosed: Callback<Option<SnackbarCloseReason>>) -> Self {
+        self.onclosed = onclosed;
+        self
+    }
+}
+
+/// Messages sent to [`SnackbarQueue`]
+pub enum Request {
+    /// Queues a notification
+    Enqueue(Notification),
+    /// The notification being shown has closed with this reason
+    Closed(Option<SnackbarCloseReason>),
+}
+
+/// Messages sent by [`SnackbarQueue`] to [`MatSnackbarHost`]
+#[derive(Clone)]
+pub enum HostCommand {
+    /// Show this notification once the previous one has closed
+    Show(Notification),
+    /// Close the notification being shown, to show one with a higher priority
+    Preempt,
+}
+
+/// The agent holding the queue of notifications
+///
+/// It's usually used through [`notify`], [`notify_with_action`] and
+/// [`enqueue`]. Notifications sent while no [`MatSnackbarHost`] is rendered
+/// are lost.
+pub struct SnackbarQueue {
+    link: AgentLink<Self>,
+    host: Option<HandlerId>,
+    queue: VecDeque<Notification>,
+    current: Option<Notification>,
+}
+
+impl Agent for SnackbarQueue {
+    type Reach = Context<Self>;
+    type Message = ();
+    type Input = Request;
+    type Output = HostCommand;
+
+    fn create(link: AgentLink<Self>) -> Self {
+        Self {
+            link,
+            host: None,
+            queue: VecDeque::new(),
+            current: None,
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) {}
+
+    fn connected(&mut self, id: HandlerId) {
+        // only the host component is bridged, dispatchers aren't respondable
+        if id.is_respondable() {
+            self.host = Some(id);
+            self.show_next();
+        }
+    }
+
+    fn handle_input(&mut self, msg: Self::Input, _id: HandlerId) {
+        match msg {
+            Request::Enqueue(notification) => {
+                let preempts = matches!(
+                    self.current.as_ref(),
+                    Some(current) if notification.priority > current.priority
+                );
+                self.queue.push_back(notification);
+                if preempts {
+                    if let Some(host) = self.host {
+                        self.link.respond(host, HostCommand::Preempt);
+                    }
+                } else {
+                    self.show_next();
+                }
+            }
+            Request::Closed(reason) => {
+                if let Some(current) = self.current.take() {
+                    if reason == Some(SnackbarCloseReason::Action) {
+                        if let Some((_, onaction)) = current.action.as_ref() {
+                            onaction.emit(());
+                        }
+                    }
+                    current.onclosed.emit(reason);
+                }
+                self.show_next();
+            }
+        }
+    }
+
+    fn disconnected(&mut self, id: HandlerId) {
+        if self.host == Some(id) {
+            self.host = None;
+            if let Some(current) = self.current.take() {
+                current.onclosed.emit(None);
+            }
+        }
+    }
+}
+
+impl SnackbarQueue {
+    /// Sends the queued notification with the highest priority to the host if
+    /// nothing is shown
+    fn show_next(&mut self) {
+        let host = match self.host {
+            Some(host) if self.current.is_none() => host,
+            _ => return,
+        };
+        let priority = match self.queue.iter().map(|it| it.priority).max() {
+            Some(priority) => priority,
+            None => return,
+        };
+        let index = self
+            .queue
+            .iter()
+            .position(|it| it.priority == priority)
+            .unwrap();
+        let notification = self.queue.remove(index).unwrap();
+        self.current = Some(notification.clone());
+        self.link.respond(host, HostCommand::Show(notification));
+    }
+}
+
+/// Renders the [`MatSnackbar`] showing the notifications of [`SnackbarQueue`]
+///
+/// It must be rendered once. Rendering it more than once shows the
+/// notifications in the host rendered last.
+pub struct MatSnackbarHost {
+    props: SnackbarHostProps,
+    queue: Box<dyn Bridge<SnackbarQueue>>,
+    snackbar_link: WeakComponentLink<MatSnackbar>,
+    current: Option<Notification>,
+    /// Whether the snackbar has started opening for `current`
+    opening: bool,
+    /// Whether `current` must be closed as soon as it opens
+    preempted: bool,
+    // `MatSnackbar` binds its listeners again when its callbacks change
+    onopening: Callback<()>,
+    onclosed: Callback<Option<SnackbarCloseReason>>,
+}
+
+/// Props for [`MatSnackbarHost`]
+#[derive(Properties, Clone)]
+pub struct SnackbarHostProps {
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub stacked: bool,
+    #[prop_or_default]
+    pub leading: bool,
+    #[prop_or_default]
+    pub close_on_escape: bool,
+}
+
+pub enum Msg {
+    Command(HostCommand),
+    Opening,
+    Closed(Option<SnackbarCloseReason>),
+}
+
+impl Component for MatSnackbarHost {
+    type Message = Msg;
+    type Properties = SnackbarHostProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let queue = SnackbarQueue::bridge(link.callback(Msg::Command));
+        let onopening = link.callback(|_| Msg::Opening);
+        let onclosed = link.callback(Msg::Closed);
+        Self {
+            props,
+            queue,
+            snackbar_link: WeakComponentLink::default(),
+            current: None,
+            opening: false,
+            preempted: false,
+            onopening,
+            onclosed,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Command(HostCommand::Show(notification)) => {
+                // a preemption received after the previous notification
+                // closed on its own was meant for it
+                self.opening = false;
+                self.preempted = false;
+                self.current = Some(notification);
+                true
+            }
+            Msg::Command(HostCommand::Preempt) => {
+                // a snackbar which hasn't started opening can't be closed
+                if self.opening {
+                    self.preempt();
+                } else {
+                    self.preempted = true;
+                }
+                false
+            }
+            Msg::Opening => {
+                self.opening = true;
+                if self.preempted {
+                    self.preempt();
+                }
+                false
+            }
+            Msg::Closed(reason) => {
+                // the snackbar mustn't be opened again by a render before the
+                // next notification is received
+                self.current = None;
+                self.opening = false;
+                self.preempted = false;
+                self.queue.send(Request::Closed(reason));
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let (message, timeout_ms, action_label) = match self.current.as_ref() {
+            Some(notification) => (
+                notification.message.clone(),
+                notification.timeout_ms,
+                notification.action.as_ref().map(|(label, _)| label.clone()),
+            ),
+            None => (Cow::from(""), 5000, None),
+        };
+        html! {
+            <MatSnackbar
+                classes=self.props.classes.clone()
+                open=self.current.is_some()
+                label_text=message
+                timeout_ms=timeout_ms
+                stacked=self.props.stacked
+                leading=self.props.leading
+                close_on_escape=self.props.close_on_escape
+                action_label=action_label
+                snackbar_link=self.snackbar_link.clone()
+                onopening=self.onopening.clone()
+                onclosed=self.onclosed.clone()
+            />
+        }
+    }
+}
+
+impl MatSnackbarHost {
+    fn preempt(&mut self) {
+        self.preempted = false;
+        self.snackbar_link
+            .close(SnackbarCloseReason::Custom(PREEMPTED_REASON.to_string()));
+    }
+}
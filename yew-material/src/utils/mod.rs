@@ -1,2 +1,7 @@
 mod weak_component_link;
 pub use weak_component_link::*;
+
+#[cfg(any(feature = "list", feature = "dialog", feature = "menu-button"))]
+mod update_complete;
+#[cfg(any(feature = "list", feature = "dialog", feature = "menu-button"))]
+pub(crate) use update_complete::update_complete;
@@ -0,0 +1,14 @@
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Waits until `element`, an MWC element, has finished its pending update.
+///
+/// The shadow root of an element is only rendered once it has.
+pub(crate) async fn update_complete(element: &JsValue) {
+    let promise = js_sys::Reflect::get(element, &JsValue::from("updateComplete"))
+        .ok()
+        .and_then(|promise| promise.dyn_into::<js_sys::Promise>().ok());
+    if let Some(promise) = promise {
+        let _ = JsFuture::from(promise).await;
+    }
+}